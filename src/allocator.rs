@@ -0,0 +1,295 @@
+use std::alloc::{self, Layout};
+use std::ptr::{self, NonNull};
+
+/// Source of the memory for [`MyVec`](crate::MyVec) buffers, see
+/// [`MyVec::new_in`](crate::MyVec::new_in).
+///
+/// Growing and shrinking default to allocating a new block, copying and freeing the old one.
+/// Allocators that can resize in place should override them.
+///
+/// # Safety
+///
+/// A block returned by the allocator must stay valid and mustn't be handed out again until it's
+/// passed back to `deallocate`, `grow` or `shrink` of the same allocator or one of its clones.
+pub unsafe trait Allocator {
+    /// Allocates a block fitting `layout`, or returns `None` when out of memory.
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    /// Like [`Allocator::allocate`], but the block is filled with zeros.
+    fn allocate_zeroed(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let pointer = self.allocate(layout)?;
+        unsafe { ptr::write_bytes(pointer.as_ptr(), 0, layout.size()) };
+        Some(pointer)
+    }
+
+    /// Frees the block at `pointer`.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must be a block allocated by this allocator with `layout`.
+    unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout);
+
+    /// Moves the block to a larger `new_layout`, keeping its contents. On failure `None` is
+    /// returned and the old block stays valid.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must be a block allocated by this allocator with `old_layout`, and `new_layout`
+    /// must have the same alignment and at least the same size.
+    unsafe fn grow(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        unsafe { reallocate(self, pointer, old_layout, new_layout) }
+    }
+
+    /// Moves the block to a smaller `new_layout`, keeping the contents that fit. On failure
+    /// `None` is returned and the old block stays valid.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must be a block allocated by this allocator with `old_layout`, and `new_layout`
+    /// must have the same alignment and at most the same size.
+    unsafe fn shrink(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        unsafe { reallocate(self, pointer, old_layout, new_layout) }
+    }
+}
+
+/// Fallback for growing and shrinking, copies into a new block and frees the old one.
+unsafe fn reallocate<A: Allocator + ?Sized>(
+    allocator: &A,
+    pointer: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> Option<NonNull<u8>> {
+    let new_pointer = allocator.allocate(new_layout)?;
+    unsafe {
+        let count = old_layout.size().min(new_layout.size());
+        ptr::copy_nonoverlapping(pointer.as_ptr(), new_pointer.as_ptr(), count);
+        allocator.deallocate(pointer, old_layout);
+    }
+    Some(new_pointer)
+}
+
+/// The global allocator registered with `#[global_allocator]`, the default for
+/// [`MyVec`](crate::MyVec).
+///
+/// Buffers from it can be handed over to std `Vec` and `Box` without copying.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+/// Zero sized blocks are never allocated, they're just aligned dangling pointers.
+fn dangling(layout: Layout) -> NonNull<u8> {
+    unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) }
+}
+
+unsafe impl Allocator for Global {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        if layout.size() == 0 {
+            return Some(dangling(layout));
+        }
+        NonNull::new(unsafe { alloc::alloc(layout) })
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Option<NonNull<u8>> {
+        if layout.size() == 0 {
+            return Some(dangling(layout));
+        }
+        NonNull::new(unsafe { alloc::alloc_zeroed(layout) })
+    }
+
+    unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            unsafe { alloc::dealloc(pointer.as_ptr(), layout) };
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        if old_layout.size() == 0 {
+            return self.allocate(new_layout);
+        }
+        // NOTE: `realloc` keeps the alignment of the old layout, which matches the new one.
+        NonNull::new(unsafe { alloc::realloc(pointer.as_ptr(), old_layout, new_layout.size()) })
+    }
+
+    unsafe fn shrink(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        if new_layout.size() == 0 {
+            unsafe { self.deallocate(pointer, old_layout) };
+            return Some(dangling(new_layout));
+        }
+        NonNull::new(unsafe { alloc::realloc(pointer.as_ptr(), old_layout, new_layout.size()) })
+    }
+}
+
+// NOTE: Shared references let several vectors use one arena or pool that outlives them.
+unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        (**self).allocate(layout)
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Option<NonNull<u8>> {
+        (**self).allocate_zeroed(layout)
+    }
+
+    unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout) {
+        unsafe { (**self).deallocate(pointer, layout) }
+    }
+
+    unsafe fn grow(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        unsafe { (**self).grow(pointer, old_layout, new_layout) }
+    }
+
+    unsafe fn shrink(
+        &self,
+        pointer: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        unsafe { (**self).shrink(pointer, old_layout, new_layout) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+
+    use super::{Allocator, Global};
+    use crate::MyVec;
+
+    /// Counts the calls going through to the global allocator.
+    #[derive(Default)]
+    struct Counting {
+        allocations: Cell<usize>,
+        deallocations: Cell<usize>,
+        resizes: Cell<usize>,
+    }
+
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            self.allocations.set(self.allocations.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout) {
+            self.deallocations.set(self.deallocations.get() + 1);
+            unsafe { Global.deallocate(pointer, layout) }
+        }
+
+        unsafe fn grow(
+            &self,
+            pointer: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Option<NonNull<u8>> {
+            self.resizes.set(self.resizes.get() + 1);
+            unsafe { Global.grow(pointer, old_layout, new_layout) }
+        }
+
+        unsafe fn shrink(
+            &self,
+            pointer: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Option<NonNull<u8>> {
+            self.resizes.set(self.resizes.get() + 1);
+            unsafe { Global.shrink(pointer, old_layout, new_layout) }
+        }
+    }
+
+    /// Only implements the required methods, so growing and shrinking use the fallback.
+    struct Minimal;
+
+    unsafe impl Allocator for Minimal {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(pointer, layout) }
+        }
+    }
+
+    #[test]
+    fn buffer_goes_through_allocator() {
+        let counting = Counting::default();
+        {
+            let mut vec = MyVec::new_in(&counting);
+            assert_eq!(counting.allocations.get(), 0);
+            for i in 0..10 {
+                vec.push(i);
+            }
+            assert_eq!(counting.allocations.get(), 1);
+            assert!(counting.resizes.get() > 0);
+
+            vec.truncate(2);
+            vec.shrink_to_fit();
+            vec.clear();
+            vec.shrink_to_fit();
+            // NOTE: Shrinking to zero gives the buffer back.
+            assert_eq!(counting.deallocations.get(), 1);
+            vec.push(1);
+        }
+        assert_eq!(counting.allocations.get(), 2);
+        assert_eq!(counting.deallocations.get(), 2);
+    }
+
+    #[test]
+    fn derived_vectors_share_allocator() {
+        let counting = Counting::default();
+        {
+            let mut vec = MyVec::with_capacity_in(4, &counting);
+            vec.extend(["a", "b", "c", "d"].map(String::from));
+            let copy = vec.clone();
+            let tail = vec.split_off(2);
+            assert_eq!(copy, ["a", "b", "c", "d"]);
+            assert_eq!(tail.repeat(2), ["c", "d", "c", "d"]);
+            assert_eq!(tail, ["c", "d"]);
+
+            let mut iter = vec.into_iter();
+            assert_eq!(iter.next().as_deref(), Some("a"));
+            let rest = MyVec::from(iter);
+            assert_eq!(rest, ["b"]);
+            assert!(std::ptr::eq(*rest.allocator(), &counting));
+        }
+        assert_eq!(counting.allocations.get(), 4);
+        assert_eq!(counting.deallocations.get(), 4);
+    }
+
+    #[test]
+    fn fallback_resizes_copy() {
+        let mut vec = MyVec::new_in(Minimal);
+        vec.extend(0..100_u32);
+        vec.truncate(3);
+        vec.shrink_to_fit();
+        assert_eq!(vec, [0, 1, 2]);
+        assert_eq!(vec.capacity(), 3);
+
+        let (pointer, length, capacity, allocator) = vec.into_raw_parts_with_alloc();
+        let vec = unsafe { MyVec::from_raw_parts_in(pointer, length, capacity, allocator) };
+        assert_eq!(vec, [0, 1, 2]);
+    }
+}
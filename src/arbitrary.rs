@@ -0,0 +1,37 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::MyVec;
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for MyVec<T> {
+    /// Generates elements the same way as for std `Vec`, so fuzz inputs stay interchangeable.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::MyVec;
+
+    const DATA: &[u8] = &[1, 10, 1, 20, 1, 30, 0, 7, 7];
+
+    #[test]
+    fn same_as_std_vec() {
+        let expected = Vec::<u8>::arbitrary(&mut Unstructured::new(DATA)).unwrap();
+        let vec = MyVec::<u8>::arbitrary(&mut Unstructured::new(DATA)).unwrap();
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn take_rest_same_as_std_vec() {
+        let expected = Vec::<u16>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+        let vec = MyVec::<u16>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+        assert_eq!(vec, expected);
+    }
+}
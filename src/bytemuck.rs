@@ -0,0 +1,139 @@
+use std::mem::{align_of, size_of};
+use std::ptr;
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::MyVec;
+
+impl<T: Pod> MyVec<T> {
+    /// Reinterprets the elements as `U`, reusing the buffer without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cast isn't possible, see [`MyVec::try_cast`].
+    pub fn cast<U: Pod>(self) -> MyVec<U> {
+        match self.try_cast() {
+            Ok(vec) => vec,
+            Err((err, _)) => panic!("{err}"),
+        }
+    }
+
+    /// Reinterprets the elements as `U`, reusing the buffer without copying.
+    ///
+    /// The buffer is freed with the layout of `U`, so both types need the same alignment and
+    /// the length and capacity in bytes must be multiples of the size of `U`. Otherwise the
+    /// vector is returned back unchanged with the reason.
+    pub fn try_cast<U: Pod>(self) -> Result<MyVec<U>, (PodCastError, MyVec<T>)> {
+        if align_of::<T>() != align_of::<U>() {
+            return Err((PodCastError::AlignmentMismatch, self));
+        }
+        if size_of::<T>() == size_of::<U>() {
+            let (pointer, length, capacity) = self.into_raw_parts();
+            return Ok(unsafe { MyVec::from_raw_parts(pointer.cast(), length, capacity) });
+        }
+        if size_of::<T>() == 0 || size_of::<U>() == 0 {
+            return Err((PodCastError::SizeMismatch, self));
+        }
+
+        let byte_len = self.len() * size_of::<T>();
+        let byte_capacity = self.capacity() * size_of::<T>();
+        let size = size_of::<U>();
+        if !byte_len.is_multiple_of(size) || !byte_capacity.is_multiple_of(size) {
+            return Err((PodCastError::OutputSliceWouldHaveSlop, self));
+        }
+
+        let (pointer, _, _) = self.into_raw_parts();
+        Ok(unsafe { MyVec::from_raw_parts(pointer.cast(), byte_len / size, byte_capacity / size) })
+    }
+
+    /// Returns the bytes of the elements.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the bytes of the elements mutably, every bit pattern written is a valid `T`.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Creates a vector from the bytes of its elements. The bytes are copied, because `bytes`
+    /// doesn't have to be aligned for `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bytes` isn't a multiple of the size of `T`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let size = size_of::<T>();
+        assert!(
+            size != 0 && bytes.len().is_multiple_of(size),
+            "byte length {} isn't a multiple of the element size {size}",
+            bytes.len(),
+        );
+
+        let length = bytes.len() / size;
+        let mut vec = Self::with_capacity(length);
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), vec.as_mut_ptr().cast::<u8>(), bytes.len());
+            vec.set_len(length);
+        }
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::PodCastError;
+
+    use crate::MyVec;
+
+    #[test]
+    fn cast_reuses_buffer() {
+        let vec = MyVec::from([[1_u32, 2], [3, 4]]);
+        let pointer = vec.as_ptr().cast::<u32>();
+
+        let flat = vec.cast::<u32>();
+        assert_eq!(flat, [1, 2, 3, 4]);
+        assert_eq!(flat.capacity(), 4);
+        assert_eq!(flat.as_ptr(), pointer);
+
+        let floats = flat.cast::<f32>();
+        assert_eq!(floats[0].to_bits(), 1);
+    }
+
+    #[test]
+    fn try_cast_rejects_incompatible() {
+        let vec = MyVec::from([1_u32, 2, 3]);
+
+        let Err((err, vec)) = vec.try_cast::<u8>() else {
+            panic!("alignment differs");
+        };
+        assert_eq!(err, PodCastError::AlignmentMismatch);
+
+        let Err((err, vec)) = vec.try_cast::<[u32; 2]>() else {
+            panic!("length isn't a multiple of two");
+        };
+        assert_eq!(err, PodCastError::OutputSliceWouldHaveSlop);
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let mut vec = MyVec::from([1_u16, 0x0203]);
+        let expected: Vec<u8> = [1_u16, 0x0203]
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect();
+        assert_eq!(vec.as_bytes(), expected);
+
+        vec.as_bytes_mut()[..2].copy_from_slice(&7_u16.to_ne_bytes());
+        assert_eq!(vec, [7, 0x0203]);
+
+        assert_eq!(MyVec::<u16>::from_bytes(vec.as_bytes()), vec);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_bytes_with_slop() {
+        MyVec::<u16>::from_bytes(&[1, 2, 3]);
+    }
+}
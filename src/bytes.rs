@@ -0,0 +1,119 @@
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+
+use crate::{MyCursor, MyVec};
+
+/// Reading consumes bytes from the front of the vector. Advancing moves the remaining bytes
+/// down, so prefer [`MyCursor`] for many small reads.
+impl Buf for MyVec<u8> {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `count > remaining`.
+    fn advance(&mut self, count: usize) {
+        assert!(count <= self.len(), "cannot advance past `remaining`");
+        self.drain(..count);
+    }
+}
+
+/// Writing appends bytes to the end of the vector, growing it when the spare capacity runs out.
+unsafe impl BufMut for MyVec<u8> {
+    fn remaining_mut(&self) -> usize {
+        // NOTE: Allocations can't be larger than `isize::MAX` bytes.
+        isize::MAX as usize - self.len()
+    }
+
+    /// # Safety
+    ///
+    /// The first `count` bytes of the spare capacity must be initialized.
+    unsafe fn advance_mut(&mut self, count: usize) {
+        let remaining = self.capacity() - self.len();
+        assert!(count <= remaining, "cannot advance past the spare capacity");
+        unsafe { self.set_len(self.len() + count) };
+    }
+
+    /// Returns the spare capacity, reserving more first when the vector is full.
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.len() == self.capacity() {
+            self.reserve(64);
+        }
+        UninitSlice::uninit(self.spare_capacity_mut())
+    }
+
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+
+    fn put_bytes(&mut self, value: u8, count: usize) {
+        self.resize(self.len() + count, value);
+    }
+}
+
+/// Reading moves the position forward without touching the bytes.
+impl Buf for MyCursor {
+    fn remaining(&self) -> usize {
+        self.remaining_slice().len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.remaining_slice()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `count > remaining`.
+    fn advance(&mut self, count: usize) {
+        assert!(count <= self.remaining(), "cannot advance past `remaining`");
+        self.set_position(self.position() + count as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BufMut};
+
+    use crate::{MyCursor, MyVec};
+
+    #[test]
+    fn put_and_get() {
+        let mut vec = MyVec::new();
+        vec.put_u16(0x0102);
+        vec.put_slice(b"ab");
+        vec.put_bytes(0, 2);
+        vec.put_u32_le(7);
+        assert_eq!(vec, [1, 2, b'a', b'b', 0, 0, 7, 0, 0, 0]);
+
+        assert_eq!(vec.get_u16(), 0x0102);
+        assert_eq!(vec.copy_to_bytes(2).as_ref(), b"ab");
+        vec.advance(2);
+        assert_eq!(vec.get_u32_le(), 7);
+        assert!(!vec.has_remaining());
+    }
+
+    #[test]
+    fn write_into_spare_capacity() {
+        let mut vec: MyVec<u8> = MyVec::with_capacity(4);
+        vec.chunk_mut().copy_from_slice(b"wxyz");
+        unsafe { vec.advance_mut(4) };
+        assert_eq!(vec, *b"wxyz");
+
+        assert_eq!(vec.chunk_mut().len(), 64);
+        assert_eq!(vec.len(), 4);
+    }
+
+    #[test]
+    fn read_through_cursor() {
+        let mut cursor = MyCursor::new(MyVec::from([0, 0, 0, 5, 9]));
+        assert_eq!(cursor.get_u32(), 5);
+        assert_eq!(cursor.remaining(), 1);
+        assert_eq!(cursor.get_u8(), 9);
+        assert_eq!(cursor.into_inner(), [0, 0, 0, 5, 9]);
+    }
+}
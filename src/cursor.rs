@@ -0,0 +1,169 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+use crate::MyVec;
+
+/// Reader over the bytes of a [`MyVec<u8>`], keeping track of the current position.
+///
+/// Position can be moved past the end with [`Seek`], reads from there return no bytes.
+pub struct MyCursor {
+    vec: MyVec<u8>,
+    position: u64,
+}
+
+impl MyCursor {
+    /// Creates a cursor starting at the first byte of `vec`.
+    pub fn new(vec: MyVec<u8>) -> Self {
+        Self { vec, position: 0 }
+    }
+
+    pub fn into_inner(self) -> MyVec<u8> {
+        self.vec
+    }
+
+    pub fn get_ref(&self) -> &MyVec<u8> {
+        &self.vec
+    }
+
+    pub fn get_mut(&mut self) -> &mut MyVec<u8> {
+        &mut self.vec
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    /// Returns the bytes from the current position to the end, empty when past the end.
+    pub fn remaining_slice(&self) -> &[u8] {
+        let start = usize::try_from(self.position)
+            .map_or(self.vec.len(), |position| position.min(self.vec.len()));
+        &self.vec[start..]
+    }
+}
+
+impl Read for MyCursor {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining_slice();
+        let count = remaining.len().min(buf.len());
+        buf[..count].copy_from_slice(&remaining[..count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        let remaining = self.remaining_slice();
+        if remaining.len() < buf.len() {
+            // NOTE: Position stays put, so the caller can still read what is left.
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        buf.copy_from_slice(&remaining[..buf.len()]);
+        self.position += buf.len() as u64;
+        Ok(())
+    }
+}
+
+impl BufRead for MyCursor {
+    /// Whole vector is already in memory, so the buffer is simply the rest of the bytes.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount as u64;
+    }
+}
+
+impl Seek for MyCursor {
+    /// Moves the position, seeking before the first byte is an [`io::ErrorKind::InvalidInput`]
+    /// error and leaves the position unchanged.
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(position) => {
+                self.position = position;
+                return Ok(position);
+            }
+            SeekFrom::End(offset) => (self.vec.len() as u64, offset),
+            SeekFrom::Current(offset) => (self.position, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, Read, Seek, SeekFrom};
+
+    use crate::{MyCursor, MyVec};
+
+    fn cursor_of(bytes: &[u8]) -> MyCursor {
+        MyCursor::new(MyVec::from(bytes))
+    }
+
+    #[test]
+    fn read_to_end() {
+        let mut cursor = cursor_of(b"hello");
+
+        let mut buf = [0; 2];
+        assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"he");
+
+        let mut rest = Vec::new();
+        cursor.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"llo");
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert_eq!(cursor.position(), 5);
+    }
+
+    #[test]
+    fn read_exact_past_end() {
+        let mut cursor = cursor_of(b"abc");
+
+        let mut buf = [0; 4];
+        assert!(cursor.read_exact(&mut buf).is_err());
+        assert_eq!(cursor.position(), 0);
+        cursor.read_exact(&mut buf[..3]).unwrap();
+        assert_eq!(&buf[..3], b"abc");
+    }
+
+    #[test]
+    fn buf_read_lines() {
+        let cursor = cursor_of(b"first\nsecond\n");
+
+        let lines: Vec<String> = cursor.lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["first", "second"]);
+    }
+
+    #[test]
+    fn seek_around() {
+        let mut cursor = cursor_of(b"abcdef");
+
+        assert_eq!(cursor.seek(SeekFrom::End(-2)).unwrap(), 4);
+        assert_eq!(cursor.fill_buf().unwrap(), b"ef");
+        assert_eq!(cursor.seek(SeekFrom::Current(-3)).unwrap(), 1);
+        assert_eq!(cursor.fill_buf().unwrap(), b"bcdef");
+        assert!(cursor.seek(SeekFrom::Current(-2)).is_err());
+        assert_eq!(cursor.position(), 1);
+
+        assert_eq!(cursor.seek(SeekFrom::Start(10)).unwrap(), 10);
+        assert!(cursor.fill_buf().unwrap().is_empty());
+        assert_eq!(cursor.into_inner(), *b"abcdef");
+    }
+}
@@ -0,0 +1,157 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::ptr;
+
+use crate::{Allocator, Global, MyVec};
+
+/// Iterator that removes a range of elements from a [`MyVec`] and yields them by value.
+///
+/// Created by [`MyVec::drain`]. When dropped, the elements that weren't yielded are dropped and
+/// the tail behind the range is moved back to close the gap.
+pub struct Drain<'a, T, A: Allocator = Global> {
+    /// While the iterator is alive `vec.length` only covers the elements before the range, so
+    /// leaking the iterator leaks the rest instead of dropping them twice.
+    pub(crate) vec: &'a mut MyVec<T, A>,
+    /// Elements in `start..end` are still owned by the iterator and not yet yielded.
+    start: usize,
+    end: usize,
+    /// Position and length of the elements that follow the drained range.
+    pub(crate) tail_start: usize,
+    pub(crate) tail_len: usize,
+}
+
+impl<'a, T, A: Allocator> Drain<'a, T, A> {
+    pub(crate) fn new(vec: &'a mut MyVec<T, A>, range: Range<usize>) -> Self {
+        let tail_len = vec.length - range.end;
+        vec.length = range.start;
+        Self {
+            vec,
+            start: range.start,
+            end: range.end,
+            tail_start: range.end,
+            tail_len,
+        }
+    }
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // NOTE: Bumping `start` hands ownership of the element over to the caller.
+        let element = unsafe { self.vec.pointer.as_ptr().add(self.start).read() };
+        self.start += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        Some(unsafe { self.vec.pointer.as_ptr().add(self.end).read() })
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        /// Moves the tail back even if dropping one of the remaining elements panics.
+        struct DropGuard<'r, 'a, T, A: Allocator>(&'r mut Drain<'a, T, A>);
+
+        impl<T, A: Allocator> Drop for DropGuard<'_, '_, T, A> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let vec = &mut *drain.vec;
+                let start = vec.length;
+                if drain.tail_start != start {
+                    unsafe {
+                        let base = vec.pointer.as_ptr();
+                        ptr::copy(base.add(drain.tail_start), base.add(start), drain.tail_len);
+                    }
+                }
+                vec.length = start + drain.tail_len;
+            }
+        }
+
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.vec.pointer.as_ptr().add(self.start) },
+            self.end - self.start,
+        );
+        self.start = self.end;
+
+        let _guard = DropGuard(self);
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MyVec;
+
+    fn vec_of(elements: &[&str]) -> MyVec<String> {
+        let mut vec = MyVec::new();
+        for element in elements {
+            vec.push(element.to_string());
+        }
+        vec
+    }
+
+    #[test]
+    fn drain_range() {
+        let mut vec = vec_of(&["a", "b", "c", "d", "e"]);
+
+        let drained: Vec<String> = vec.drain(1..3).collect();
+        assert_eq!(drained, ["b", "c"]);
+        assert_eq!(vec.as_slice(), &["a", "d", "e"]);
+    }
+
+    #[test]
+    fn drain_from_both_ends() {
+        let mut vec = vec_of(&["a", "b", "c", "d"]);
+
+        let mut drain = vec.drain(..);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next_back().as_deref(), Some("d"));
+        assert_eq!(drain.next().as_deref(), Some("a"));
+        assert_eq!(drain.len(), 2);
+        drop(drain);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn drain_dropped_early_keeps_tail() {
+        let mut vec = vec_of(&["a", "b", "c", "d", "e"]);
+
+        let mut drain = vec.drain(1..=3);
+        assert_eq!(drain.next().as_deref(), Some("b"));
+        drop(drain);
+        assert_eq!(vec.as_slice(), &["a", "e"]);
+    }
+
+    #[test]
+    fn drain_leaked_keeps_head() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+
+        std::mem::forget(vec.drain(1..));
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+}
@@ -0,0 +1,25 @@
+use std::alloc::Layout;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by fallible allocating methods like [`MyVec::try_reserve`](crate::MyVec::try_reserve).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// Requested capacity overflowed `usize` or exceeds `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The allocator couldn't provide memory for `layout`.
+    AllocError { layout: Layout },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => f.write_str("capacity overflow"),
+            TryReserveError::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl Error for TryReserveError {}
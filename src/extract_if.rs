@@ -0,0 +1,120 @@
+use std::ptr;
+
+use crate::{Allocator, Global, MyVec};
+
+/// Iterator that removes and yields the elements of a [`MyVec`] matching a predicate.
+///
+/// Created by [`MyVec::extract_if`]. Elements are visited lazily, so if the iterator is dropped
+/// before it's exhausted the unvisited elements stay in the vector.
+pub struct ExtractIf<'a, T, F, A: Allocator = Global>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    /// While the iterator is alive `vec.length` is zero, the iterator owns every element.
+    vec: &'a mut MyVec<T, A>,
+    /// Index of the next element to visit.
+    index: usize,
+    /// Number of elements extracted so far, kept elements are shifted left by this amount.
+    deleted: usize,
+    original_len: usize,
+    predicate: F,
+}
+
+impl<'a, T, F, A: Allocator> ExtractIf<'a, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(vec: &'a mut MyVec<T, A>, predicate: F) -> Self {
+        let original_len = vec.length;
+        vec.length = 0;
+        Self {
+            vec,
+            index: 0,
+            deleted: 0,
+            original_len,
+            predicate,
+        }
+    }
+}
+
+impl<T, F, A: Allocator> Iterator for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let base = self.vec.pointer.as_ptr();
+        while self.index < self.original_len {
+            unsafe {
+                let current = base.add(self.index);
+                // NOTE: `index` is bumped after the predicate, so if it panics the current
+                // element is treated as unvisited and kept.
+                let matched = (self.predicate)(&mut *current);
+                self.index += 1;
+                if matched {
+                    self.deleted += 1;
+                    return Some(current.read());
+                }
+                if self.deleted > 0 {
+                    ptr::copy_nonoverlapping(current, current.sub(self.deleted), 1);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.original_len - self.index))
+    }
+}
+
+impl<T, F, A: Allocator> Drop for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // NOTE: Unvisited elements are moved next to the kept ones.
+        if self.index < self.original_len && self.deleted > 0 {
+            unsafe {
+                let base = self.vec.pointer.as_ptr();
+                ptr::copy(
+                    base.add(self.index),
+                    base.add(self.index - self.deleted),
+                    self.original_len - self.index,
+                );
+            }
+        }
+        self.vec.length = self.original_len - self.deleted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MyVec;
+
+    #[test]
+    fn extract_matching() {
+        let mut vec = MyVec::new();
+        for i in 0..8 {
+            vec.push(i.to_string());
+        }
+
+        let extracted: Vec<String> = vec.extract_if(|element| element.as_str() < "4").collect();
+        assert_eq!(extracted, ["0", "1", "2", "3"]);
+        assert_eq!(vec.as_slice(), &["4", "5", "6", "7"]);
+    }
+
+    #[test]
+    fn extract_dropped_early() {
+        let mut vec = MyVec::new();
+        for i in 0..6 {
+            vec.push(i.to_string());
+        }
+
+        let mut extract = vec.extract_if(|element| *element == "1" || *element == "4");
+        assert_eq!(extract.next().as_deref(), Some("1"));
+        drop(extract);
+        assert_eq!(vec.as_slice(), &["0", "2", "3", "4", "5"]);
+    }
+}
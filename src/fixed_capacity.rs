@@ -0,0 +1,125 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Allocator, Global, MyVec};
+
+/// Guard that pins the buffer of a [`MyVec`], created by [`MyVec::freeze`].
+///
+/// Only operations that never reallocate are available, growing ones hand the element back
+/// when the vector is full. Pointers into the buffer stay valid until the guard is dropped.
+pub struct FixedCapacity<'a, T, A: Allocator = Global> {
+    vec: &'a mut MyVec<T, A>,
+}
+
+impl<'a, T, A: Allocator> FixedCapacity<'a, T, A> {
+    pub(crate) fn new(vec: &'a mut MyVec<T, A>) -> Self {
+        Self { vec }
+    }
+
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Returns how many more elements fit before the vector is full.
+    pub fn remaining_capacity(&self) -> usize {
+        self.vec.capacity() - self.vec.len()
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.vec.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.vec.as_mut_ptr()
+    }
+
+    /// Appends `element`, or returns it back when the vector is full.
+    pub fn push(&mut self, element: T) -> Result<(), T> {
+        self.vec.push_within_capacity(element)
+    }
+
+    /// Inserts `element` at `index`, or returns it back when the vector is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        if self.vec.len() == self.vec.capacity() {
+            return Err(element);
+        }
+
+        // NOTE: There's room for one more element, so `insert` won't reallocate.
+        self.vec.insert(index, element);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    /// See [`MyVec::remove`].
+    pub fn remove(&mut self, index: usize) -> T {
+        self.vec.remove(index)
+    }
+
+    /// See [`MyVec::swap_remove`].
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.vec.swap_remove(index)
+    }
+
+    pub fn truncate(&mut self, new_len: usize) {
+        self.vec.truncate(new_len);
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+}
+
+impl<T, A: Allocator> Deref for FixedCapacity<'_, T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+}
+
+impl<T, A: Allocator> DerefMut for FixedCapacity<'_, T, A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.vec.as_mut_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MyVec;
+
+    #[test]
+    fn frozen_buffer_never_moves() {
+        let mut vec: MyVec<u32> = MyVec::with_capacity(3);
+        let mut frozen = vec.freeze();
+        let pointer = frozen.as_ptr();
+
+        assert_eq!(frozen.push(1), Ok(()));
+        assert_eq!(frozen.insert(0, 0), Ok(()));
+        assert_eq!(frozen.push(2), Ok(()));
+        assert_eq!(frozen.push(3), Err(3));
+        assert_eq!(frozen.insert(0, 4), Err(4));
+        assert_eq!(frozen.remaining_capacity(), 0);
+        assert_eq!(&*frozen, &[0, 1, 2]);
+
+        assert_eq!(frozen.pop(), Some(2));
+        frozen[0] = 5;
+        assert_eq!(frozen.as_ptr(), pointer);
+
+        assert_eq!(vec.as_slice(), &[5, 1]);
+        assert_eq!(vec.as_ptr(), pointer);
+    }
+}
@@ -0,0 +1,253 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr::{self, NonNull};
+
+use crate::{Allocator, Global, MyVec};
+
+/// Iterator that moves elements out of a [`MyVec`], created by its `IntoIterator` impl.
+///
+/// The iterator owns the buffer; when dropped, elements that weren't yielded are dropped and the
+/// buffer is freed.
+pub struct IntoIter<T, A: Allocator = Global> {
+    pointer: NonNull<T>,
+    capacity: usize,
+    /// Elements in `start..end` are not yet yielded.
+    start: usize,
+    end: usize,
+    /// Taken out once, when the buffer is freed or handed back to a vector.
+    allocator: ManuallyDrop<A>,
+    /// Iterator owns the remaining elements, see the marker on [`MyVec`].
+    marker: PhantomData<T>,
+}
+
+// NOTE: Same reasoning as for `MyVec`, the iterator uniquely owns its elements.
+unsafe impl<T: Send, A: Allocator + Send> Send for IntoIter<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for IntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for MyVec<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        // NOTE: Ownership of the buffer moves to the iterator, so `Drop` of the vector mustn't run.
+        let (pointer, length, capacity, allocator) = self.into_raw_parts_with_alloc();
+        IntoIter {
+            pointer: unsafe { NonNull::new_unchecked(pointer) },
+            capacity,
+            start: 0,
+            end: length,
+            allocator: ManuallyDrop::new(allocator),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    /// Returns the elements that weren't yielded yet as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            std::slice::from_raw_parts(self.pointer.as_ptr().add(self.start), self.end - self.start)
+        }
+    }
+
+    /// Returns the elements that weren't yielded yet as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.pointer.as_ptr().add(self.start),
+                self.end - self.start,
+            )
+        }
+    }
+}
+
+impl<T, A: Allocator> From<IntoIter<T, A>> for MyVec<T, A> {
+    /// Collects the elements that weren't yielded yet, reusing the original buffer.
+    fn from(iter: IntoIter<T, A>) -> Self {
+        // NOTE: Ownership of the buffer moves back to a vector, so `Drop` of the iterator mustn't
+        // run.
+        let mut iter = ManuallyDrop::new(iter);
+        let length = iter.end - iter.start;
+        unsafe {
+            let base = iter.pointer.as_ptr();
+            if iter.start != 0 {
+                ptr::copy(base.add(iter.start), base, length);
+            }
+            let allocator = ManuallyDrop::take(&mut iter.allocator);
+            MyVec::from_raw_parts_in(base, length, iter.capacity, allocator)
+        }
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // NOTE: Bumping `start` hands ownership of the element over to the caller.
+        let element = unsafe { self.pointer.as_ptr().add(self.start).read() };
+        self.start += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        Some(unsafe { self.pointer.as_ptr().add(self.end).read() })
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+// NOTE: Same eyepatch as on `MyVec`, see its `Drop` impl.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        unsafe { self.drop_and_dealloc() }
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        unsafe { self.drop_and_dealloc() }
+    }
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    /// Drops the remaining elements and frees the buffer, shared by both `Drop` impls.
+    ///
+    /// # Safety
+    ///
+    /// The iterator mustn't be used afterwards.
+    unsafe fn drop_and_dealloc(&mut self) {
+        // NOTE: Empty vector over the same buffer frees it once it goes out of scope, also when
+        // dropping one of the remaining elements panics.
+        let _buffer = unsafe {
+            let allocator = ManuallyDrop::take(&mut self.allocator);
+            MyVec::from_raw_parts_in(self.pointer.as_ptr(), 0, self.capacity, allocator)
+        };
+
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.pointer.as_ptr().add(self.start) },
+            self.end - self.start,
+        );
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MyVec;
+
+    #[test]
+    fn consume_by_value() {
+        let mut vec = MyVec::new();
+        for i in 0..3 {
+            vec.push(i.to_string());
+        }
+
+        let mut collected = Vec::new();
+        for element in vec {
+            collected.push(element);
+        }
+        assert_eq!(collected, ["0", "1", "2"]);
+    }
+
+    #[test]
+    fn drop_partially_consumed() {
+        let mut vec = MyVec::new();
+        for i in 0..4 {
+            vec.push(i.to_string());
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.next().as_deref(), Some("0"));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn empty_vector() {
+        let vec: MyVec<String> = MyVec::new();
+        assert_eq!(vec.into_iter().next(), None);
+    }
+
+    #[test]
+    fn consume_from_both_ends() {
+        let mut vec = MyVec::new();
+        for i in 0..4 {
+            vec.push(i.to_string());
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next_back().as_deref(), Some("3"));
+        assert_eq!(iter.next().as_deref(), Some("0"));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back().as_deref(), Some("2"));
+        assert_eq!(iter.next().as_deref(), Some("1"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn reversed() {
+        let vec = MyVec::from_fn(3, |i| i);
+        let reversed: Vec<usize> = vec.into_iter().rev().collect();
+        assert_eq!(reversed, [2, 1, 0]);
+    }
+
+    #[test]
+    fn inspect_remaining() {
+        let vec = MyVec::from_fn(4, |i| i);
+        let mut iter = vec.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.as_slice(), &[1, 2]);
+
+        iter.as_mut_slice()[0] = 5;
+        assert_eq!(iter.next(), Some(5));
+    }
+
+    #[test]
+    fn back_into_vector() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(i.to_string());
+        }
+
+        let mut iter = vec.into_iter();
+        iter.next();
+        iter.next();
+        let pointer = iter.as_slice().as_ptr();
+
+        let vec = MyVec::from(iter);
+        assert_eq!(vec.as_slice(), &["2", "3", "4"]);
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(unsafe { vec.as_ptr().add(2) }, pointer);
+    }
+
+    #[test]
+    fn sent_to_another_thread() {
+        let iter = MyVec::from(["a".to_string(), "b".to_string()]).into_iter();
+        let joined = std::thread::spawn(move || iter.collect::<Vec<_>>().concat());
+        assert_eq!(joined.join().unwrap(), "ab");
+    }
+}
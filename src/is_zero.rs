@@ -0,0 +1,163 @@
+use std::alloc::Layout;
+use std::cell::Cell;
+
+use crate::{Allocator, Global, MyVec};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element types whose all-zero bit pattern is a valid value. When `my_vec![elem; n]` gets such
+/// an element and it's zero, the memory comes zeroed straight from the allocator instead of
+/// cloning into every slot.
+///
+/// Sealed, it's implemented for the primitive numbers, `bool`, `char` and thin raw pointers.
+pub trait IsZero: Copy + sealed::Sealed {
+    /// Returns `true` if every bit of the value is zero.
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_is_zero {
+    (|$value:ident| $is_zero:expr; $($ty:ty),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl IsZero for $ty {
+                fn is_zero(&self) -> bool {
+                    let $value = *self;
+                    $is_zero
+                }
+            }
+        )+
+    };
+}
+
+impl_is_zero!(|x| x == 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+// NOTE: `-0.0` compares equal to zero, but its sign bit is set.
+impl_is_zero!(|x| x.to_bits() == 0; f32, f64);
+impl_is_zero!(|x| !x; bool);
+impl_is_zero!(|x| x == '\0'; char);
+
+impl<T> sealed::Sealed for *const T {}
+impl<T> sealed::Sealed for *mut T {}
+
+// NOTE: Only thin pointers, the metadata of a wide pointer can't be zero for every pointee.
+impl<T> IsZero for *const T {
+    fn is_zero(&self) -> bool {
+        self.is_null()
+    }
+}
+
+impl<T> IsZero for *mut T {
+    fn is_zero(&self) -> bool {
+        self.is_null()
+    }
+}
+
+impl<T: IsZero> MyVec<T> {
+    /// Creates a vector of `n` zero values, the allocator hands out the memory already zeroed.
+    pub(crate) fn from_zeroed(n: usize) -> Self {
+        if n == 0 {
+            return Self::new();
+        }
+
+        let layout = Layout::array::<T>(n).expect("capacity overflow");
+        let pointer = Global.allocate_zeroed(layout).expect("Couldn't allocate.");
+        // NOTE: Every element is all zero bits, which `IsZero` guarantees is a valid `T`.
+        unsafe { Self::from_raw_parts(pointer.as_ptr().cast(), n, n) }
+    }
+}
+
+/// Argument of `my_vec![elem; n]`, picks how the elements are created.
+///
+/// Method lookup tries the by-value receiver before the reference, so [`FromElemZeroed`] is
+/// used when the element type is [`IsZero`] and [`FromElemCloned`] for everything else. In
+/// generic code the element type isn't known to be `IsZero`, and it falls back to cloning.
+#[doc(hidden)]
+pub struct FromElem<T>(Cell<Option<T>>);
+
+impl<T> FromElem<T> {
+    pub fn new(value: T) -> Self {
+        Self(Cell::new(Some(value)))
+    }
+}
+
+#[doc(hidden)]
+pub trait FromElemZeroed<T> {
+    fn into_vec(self, n: usize) -> MyVec<T>;
+}
+
+impl<T: IsZero> FromElemZeroed<T> for FromElem<T> {
+    fn into_vec(self, n: usize) -> MyVec<T> {
+        let value = self.0.into_inner().expect("element is taken once");
+        if value.is_zero() {
+            MyVec::from_zeroed(n)
+        } else {
+            MyVec::from_elem(value, n)
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait FromElemCloned<T> {
+    fn into_vec(self, n: usize) -> MyVec<T>;
+}
+
+impl<T: Clone> FromElemCloned<T> for &FromElem<T> {
+    fn into_vec(self, n: usize) -> MyVec<T> {
+        // NOTE: Reached through a reference, the cell lets us still move the element out.
+        MyVec::from_elem(self.0.take().expect("element is taken once"), n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::IsZero;
+    use crate::{my_vec, MyVec};
+
+    #[test]
+    fn zero_bit_patterns() {
+        assert!(0_u64.is_zero());
+        assert!(!1_i8.is_zero());
+        assert!(0.0_f32.is_zero());
+        assert!(!(-0.0_f64).is_zero());
+        assert!(false.is_zero());
+        assert!('\0'.is_zero());
+        assert!(ptr::null::<String>().is_zero());
+        assert!(!ptr::dangling_mut::<u8>().is_zero());
+    }
+
+    #[test]
+    fn macro_uses_zeroed_memory() {
+        let bytes = my_vec![0_u8; 1000];
+        assert_eq!(bytes.len(), 1000);
+        assert_eq!(bytes.capacity(), 1000);
+        assert!(bytes.iter().all(|&byte| byte == 0));
+
+        let numbers: MyVec<u32> = my_vec![0; 4];
+        assert_eq!(numbers, [0; 4]);
+        let pointers = my_vec![ptr::null_mut::<u8>(); 2];
+        assert!(pointers.iter().all(|pointer| pointer.is_null()));
+
+        // NOTE: Values that aren't all zero bits are still cloned into every slot.
+        let sevens = my_vec![7_i16; 3];
+        assert_eq!(sevens, [7; 3]);
+        let negative = my_vec![-0.0_f64; 2];
+        assert!(negative.iter().all(|x| x.to_bits() == (-0.0_f64).to_bits()));
+        assert!(my_vec![0_u8; 0].is_empty());
+    }
+
+    #[test]
+    fn macro_clones_other_types() {
+        fn repeated<T: Clone>(value: T) -> MyVec<T> {
+            my_vec![value; 2]
+        }
+
+        assert_eq!(repeated(0_u8), [0, 0]);
+        assert_eq!(my_vec![String::from("a"); 2], ["a", "a"]);
+        let defaults = my_vec![String::default(); 2];
+        assert_eq!(defaults, ["", ""]);
+    }
+}
@@ -1,10 +1,139 @@
-use std::alloc::{alloc, dealloc, realloc, Layout};
+#![cfg_attr(feature = "nightly", feature(dropck_eyepatch))]
+
+use std::alloc::Layout;
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ops::{
+    Add, AddAssign, Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds, RangeFrom,
+    RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
 use std::ptr;
 use std::ptr::NonNull;
+use std::rc::Rc;
+use std::sync::Arc;
+
+mod allocator;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod cursor;
+mod drain;
+mod error;
+mod extract_if;
+mod fixed_capacity;
+mod into_iter;
+mod is_zero;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "serde")]
+mod serde;
+mod splice;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+pub use allocator::{Allocator, Global};
+pub use cursor::MyCursor;
+pub use drain::Drain;
+pub use error::TryReserveError;
+pub use extract_if::ExtractIf;
+pub use fixed_capacity::FixedCapacity;
+pub use into_iter::IntoIter;
+pub use is_zero::IsZero;
+pub use splice::Splice;
+
+/// Items used by the expansion of [`my_vec!`], not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::is_zero::{FromElem, FromElemCloned, FromElemZeroed};
+}
+
+/// Creates a [`MyVec`] like `vec!` does, allocating exactly once.
+///
+/// - `my_vec![a, b, c]` moves the listed elements in.
+/// - `my_vec![elem; n]` holds `n` clones of `elem`. When `elem` is a zero [`IsZero`] value, the
+///   memory comes zeroed straight from the allocator instead of being written. The element type
+///   has to be known where the macro is used, write `my_vec![String::new(); n]` rather than
+///   `my_vec![Default::default(); n]`.
+#[macro_export]
+macro_rules! my_vec {
+    () => {
+        $crate::MyVec::new()
+    };
+    ($elem:expr; $n:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{FromElemCloned as _, FromElemZeroed as _};
+        $crate::__private::FromElem::new($elem).into_vec($n)
+    }};
+    ($($element:expr),+ $(,)?) => {
+        $crate::MyVec::from([$($element),+])
+    };
+}
 
 // `NonNull` is like raw mutable pointer, nonzero and covarant. It can never be null.
 
-pub struct MyVec<T> {
+/// Growable array that owns its elements, like std `Vec`.
+///
+/// The vector is covariant in `T`, like std `Vec`:
+///
+/// ```
+/// use impl_vec::MyVec;
+///
+/// fn shorten<'a>(vec: MyVec<&'static str>) -> MyVec<&'a str> {
+///     vec
+/// }
+///
+/// let local = String::from("local");
+/// let mut vec = shorten(MyVec::from(["static"]));
+/// vec.push(local.as_str());
+/// assert_eq!(vec, ["static", "local"]);
+/// ```
+///
+/// Elements are dropped together with the vector, so they can't borrow values that die first.
+/// On stable the plain `Drop` impl already rejects this. With the `nightly` eyepatch it's the
+/// `PhantomData<T>` marker that keeps it rejected, because the element's `Drop` reads the
+/// borrow:
+///
+/// ```compile_fail,E0597
+/// use impl_vec::MyVec;
+///
+/// struct Inspect<'a>(&'a String);
+///
+/// impl Drop for Inspect<'_> {
+///     fn drop(&mut self) {
+///         println!("{}", self.0);
+///     }
+/// }
+///
+/// let mut vec = MyVec::new();
+/// let value = String::from("value");
+/// vec.push(Inspect(&value));
+/// ```
+///
+/// The vector is only `Send` and `Sync` when its elements are:
+///
+/// ```compile_fail,E0277
+/// use std::rc::Rc;
+///
+/// use impl_vec::MyVec;
+///
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(MyVec::from([Rc::new(())]));
+/// ```
+pub struct MyVec<T, A: Allocator = Global> {
     /// Pinter to the first element in the vector. It will **always** point to that position,
     /// we don't need to offset it during usage.
     pointer: NonNull<T>,
@@ -13,145 +142,3187 @@ pub struct MyVec<T> {
     /// Allocated size for the vector without new allocation. After `length` surpasses `capacity`
     /// new allocation is necessary.
     capacity: usize,
+    /// Every allocation, reallocation and deallocation of the buffer goes through it.
+    allocator: A,
+    /// Tells the drop checker that the vector owns values of `T` and drops them, the pointer
+    /// alone doesn't say that.
+    marker: PhantomData<T>,
 }
 
+// NOTE: `NonNull` opts out of `Send` and `Sync`, but the vector owns its elements uniquely just
+// like `Box` does, so it's as thread safe as `T` itself.
+unsafe impl<T: Send, A: Allocator + Send> Send for MyVec<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for MyVec<T, A> {}
+
 impl<T> MyVec<T> {
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates an empty vector with space for exactly `capacity` elements, allocating once up
+    /// front. Zero capacity doesn't allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Like [`MyVec::with_capacity`], but returns an error instead of panicking when the capacity
+    /// overflows or the allocation fails.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a vector of `n` elements where element `i` is `f(i)`, allocating once up front.
+    /// If `f` panics, the elements created so far are dropped.
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, mut f: F) -> Self {
+        let mut vec = Self::with_capacity(n);
+        let base = vec.pointer.as_ptr();
+        let mut length = SetLenOnDrop::new(&mut vec.length);
+        while length.local_len < n {
+            unsafe { base.add(length.local_len).write(f(length.local_len)) };
+            length.local_len += 1;
+        }
+        drop(length);
+        vec
+    }
+
+    /// Creates a vector of `len` uninitialized elements, allocating once up front. Initialize
+    /// every element and then call [`MyVec::assume_init`].
+    pub fn new_uninit(len: usize) -> MyVec<MaybeUninit<T>> {
+        let mut vec = MyVec::with_capacity(len);
+        // NOTE: `MaybeUninit` doesn't need initialization and has nothing to drop.
+        vec.length = len;
+        vec
+    }
+
+    /// Creates a vector directly from a pointer, a length and a capacity, taking ownership of the
+    /// buffer.
+    ///
+    /// # Safety
+    ///
+    /// - `pointer` must come from [`MyVec::into_raw_parts`], or otherwise be allocated by the
+    ///   global allocator with `Layout::array::<T>(capacity)`. With zero `capacity` it must be
+    ///   non-null and aligned, but doesn't need to be allocated.
+    /// - `length` must be less than or equal to `capacity`, and the first `length` elements must be
+    ///   initialized.
+    /// - Nothing else may use or free the buffer afterwards.
+    ///
+    /// For zero sized `T` the capacity is ignored, there's no buffer.
+    pub unsafe fn from_raw_parts(pointer: *mut T, length: usize, capacity: usize) -> Self {
+        unsafe { Self::from_raw_parts_in(pointer, length, capacity, Global) }
+    }
+
+    /// Decomposes the vector into its pointer, length and capacity without freeing anything.
+    /// Use [`MyVec::from_raw_parts`] to get a vector back and release the memory.
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let (pointer, length, capacity, Global) = self.into_raw_parts_with_alloc();
+        (pointer, length, capacity)
+    }
+
+    /// Converts the vector into a boxed slice, shrinking the allocation to the length first.
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        self.shrink_to_fit();
+        // NOTE: Ownership of the buffer is transferred to the box, so `Drop` mustn't run.
+        let vec = ManuallyDrop::new(self);
+        // Buffer was allocated with `Layout::array::<T>(length)` just like `Box<[T]>` expects, and
+        // an empty vector has a dangling pointer which `Box` never deallocates.
+        unsafe {
+            let slice = ptr::slice_from_raw_parts_mut(vec.pointer.as_ptr(), vec.length);
+            Box::from_raw(slice)
+        }
+    }
+}
+
+impl<T, A: Allocator> MyVec<T, A> {
+    /// Zero sized elements take no memory, so the vector never allocates and has room for
+    /// `usize::MAX` of them. Only the length is counted.
+    const IS_ZST: bool = std::mem::size_of::<T>() == 0;
+
+    /// Creates an empty vector that gets its memory from `allocator`. Nothing is allocated yet.
+    pub fn new_in(allocator: A) -> Self {
         Self {
             // when `length` is zero we shouldn't user `pointer` because it dangling
             pointer: ptr::NonNull::dangling(),
             length: 0,
-            capacity: 0, // no allocation for empty vector
+            // no allocation for empty vector
+            capacity: if Self::IS_ZST { usize::MAX } else { 0 },
+            allocator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Like [`MyVec::with_capacity`], but the memory comes from `allocator`.
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        let mut vec = Self::new_in(allocator);
+        if capacity != 0 {
+            vec.set_capacity(capacity);
         }
+        vec
+    }
+
+    /// Like [`MyVec::try_with_capacity`], but the memory comes from `allocator`.
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, TryReserveError> {
+        let mut vec = Self::new_in(allocator);
+        if capacity != 0 {
+            vec.try_set_capacity(capacity)?;
+        }
+        Ok(vec)
+    }
+
+    /// Like [`MyVec::from_raw_parts`], but the buffer belongs to `allocator`.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`MyVec::from_raw_parts`], except that `pointer` must be allocated by
+    /// `allocator` with `Layout::array::<T>(capacity)`.
+    pub unsafe fn from_raw_parts_in(
+        pointer: *mut T,
+        length: usize,
+        capacity: usize,
+        allocator: A,
+    ) -> Self {
+        debug_assert!(Self::IS_ZST || length <= capacity);
+        Self {
+            pointer: unsafe { NonNull::new_unchecked(pointer) },
+            length,
+            capacity: if Self::IS_ZST { usize::MAX } else { capacity },
+            allocator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Decomposes the vector into its pointer, length, capacity and allocator without freeing
+    /// anything. Use [`MyVec::from_raw_parts_in`] to get a vector back and release the memory.
+    pub fn into_raw_parts_with_alloc(self) -> (*mut T, usize, usize, A) {
+        let vec = ManuallyDrop::new(self);
+        // NOTE: Vector is never dropped, so the allocator is moved out exactly once.
+        let allocator = unsafe { ptr::read(&vec.allocator) };
+        (vec.pointer.as_ptr(), vec.length, vec.capacity, allocator)
+    }
+
+    /// Returns the allocator backing the buffer.
+    pub fn allocator(&self) -> &A {
+        &self.allocator
     }
 
     pub fn len(&self) -> usize {
         self.length
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Forces the length of the vector to `new_len`, without dropping or initializing anything.
+    ///
+    /// # Safety
+    ///
+    /// - `new_len` must be less than or equal to [`MyVec::capacity`].
+    /// - Elements in `old_len..new_len` must be initialized.
+    /// - When shrinking, elements in `new_len..old_len` are no longer owned by the vector and
+    ///   won't be dropped by it.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.length = new_len;
+    }
+
+    /// Returns how many elements fit without reallocating, `usize::MAX` for zero sized `T`.
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
     pub fn push(&mut self, element: T) {
-        //  mem::size_of::<T> == 0 returns Err from Vec, and also sets `capacity` to maximum
-        assert_ne!(std::mem::size_of::<T>(), 0, "No zero sized types");
+        handle_reserve(self.try_push(element));
+    }
 
-        // NOTE: After this point we know that type `T` has to have a size in memory.
-        if self.capacity == 0 {
-            let layout = Layout::array::<T>(4).expect("Couldn't allocate"); // 4 elements
-
-            // layout is 4 * size_of::<T>
-            // size_of::<T> > 0
-            let pointer = unsafe { alloc(layout) } as *mut T;
-            let pointer = NonNull::new(pointer).expect("Couldn't allocate.");
-            // NOTE: `pointer` is not null and we have freshly allocated space.
-            unsafe { pointer.as_ptr().write(element) };
-            self.pointer = pointer;
-            self.capacity = 4;
-            self.length = 1;
-        } else if self.length < self.capacity {
-            // NOTE: We have enough space to add new element without new allocation
-            let offset = self
-                .length
-                .checked_mul(std::mem::size_of::<T>())
-                .expect("Can't reach memory location");
-            assert!(offset < isize::MAX as usize, "Wrapped isize");
-            // Offset can't wrap around and `pointer` is pointing to valid memory
-            // writing to an offset at `self.length` is valid
+    /// Like [`MyVec::push`], but returns an error instead of panicking when the vector can't
+    /// grow. The vector is left untouched on failure.
+    pub fn try_push(&mut self, element: T) -> Result<(), TryReserveError> {
+        if self.length == self.capacity {
+            self.try_reserve(1)?;
+        }
 
-            unsafe { self.pointer.as_ptr().add(self.length).write(element) };
+        // NOTE: We have enough space to add new element without new allocation
+        let offset = self
+            .length
+            .checked_mul(std::mem::size_of::<T>())
+            .expect("Can't reach memory location");
+        assert!(offset < isize::MAX as usize, "Wrapped isize");
+        // Offset can't wrap around and `pointer` is pointing to valid memory
+        // writing to an offset at `self.length` is valid
+
+        unsafe { self.pointer.as_ptr().add(self.length).write(element) };
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Appends `element` and returns a mutable reference to it.
+    pub fn push_get(&mut self, element: T) -> &mut T {
+        self.push_with(|| element)
+    }
+
+    /// Reserves a slot at the end, constructs the element by calling `f` directly into it, and
+    /// returns a mutable reference to the new element.
+    pub fn push_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if self.length == self.capacity {
+            self.reserve(1);
+        }
+
+        unsafe {
+            let slot = self.pointer.as_ptr().add(self.length);
+            // NOTE: If `f` panics nothing was written and `length` is unchanged.
+            slot.write(f());
             self.length += 1;
-        } else {
-            debug_assert!(self.length == self.capacity);
-
-            // NOTE: We don't have enough space, we need new allocation
-            let align = std::mem::align_of::<T>();
-
-            let size = std::mem::size_of::<T>() * self.capacity;
-            let size = size
-                .checked_add(size % align) // maybe: align - size % align
-                .expect("isize wrapped");
-            let new_capacity = self.capacity.checked_mul(2).expect("capacity wrapped");
-            let new_size_in_bytes = std::mem::size_of::<T>() * new_capacity;
-            let pointer = unsafe {
-                let layout = Layout::from_size_align_unchecked(size, align);
-                realloc(self.pointer.as_ptr() as *mut u8, layout, new_size_in_bytes)
-            };
-            // NOTE: We can panic here because old `length`, `capacity` and `pointer` are still valid.
-            let pointer = NonNull::new(pointer as *mut T).expect("Couldn't reallocate.");
+            &mut *slot
+        }
+    }
+
+    /// Appends `element` only if there's spare capacity, never allocating. Returns the element
+    /// back when the vector is full.
+    pub fn push_within_capacity(&mut self, element: T) -> Result<(), T> {
+        if self.length == self.capacity {
+            return Err(element);
+        }
+
+        unsafe { self.pointer.as_ptr().add(self.length).write(element) };
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Inserts `element` at position `index`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        handle_reserve(self.try_insert(index, element));
+    }
+
+    /// Like [`MyVec::insert`], but returns an error instead of panicking when the vector can't
+    /// grow. The vector is left untouched on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        assert!(
+            index <= self.length,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.length
+        );
+
+        if self.length == self.capacity {
+            self.try_reserve(1)?;
+        }
+
+        unsafe {
+            let slot = self.pointer.as_ptr().add(index);
+            // NOTE: Regions overlap, so `copy` (memmove) is used instead of `copy_nonoverlapping`.
+            ptr::copy(slot, slot.add(1), self.length - index);
+            slot.write(element);
+        }
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Like [`MyVec::insert`], but hands `element` back instead of panicking when `index > len`.
+    pub fn checked_insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        if index > self.length {
+            return Err(element);
+        }
+
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Inserts all elements of `iter` at position `index`, shifting the elements after it to the
+    /// right. When the iterator reports an exact size, space is reserved once and the tail is
+    /// moved a single time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_from_iter<I: IntoIterator<Item = T>>(&mut self, index: usize, iter: I) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.length
+        );
+
+        // NOTE: Splicing into an empty range only ever inserts.
+        self.splice(index..index, iter);
+    }
+
+    /// Appends every element of `iter`, returning an error instead of panicking when the vector
+    /// can't grow. On failure the elements appended so far are dropped again, so the vector keeps
+    /// its original contents.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError> {
+        let original_len = self.length;
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+
+        let result = self.try_reserve(lower_bound).and_then(|()| {
+            for element in iter {
+                self.try_push(element)?;
+            }
+            Ok(())
+        });
+        if result.is_err() {
+            self.truncate(original_len);
+        }
+        result
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to
+    /// the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "removal index (is {index}) should be < len (is {})",
+            self.length
+        );
+
+        unsafe {
+            let slot = self.pointer.as_ptr().add(index);
+            // NOTE: After reading the element out, its slot is logically uninitialized and gets
+            // overwritten by the shifted tail, so it is never dropped twice.
+            let element = slot.read();
+            ptr::copy(slot.add(1), slot, self.length - index - 1);
+            self.length -= 1;
+            element
+        }
+    }
+
+    /// Removes the elements at `indices` in a single pass and returns them in order. The remaining
+    /// elements keep their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` isn't strictly increasing or any index is out of bounds.
+    pub fn remove_indices(&mut self, indices: &[usize]) -> MyVec<T, A>
+    where
+        A: Clone,
+    {
+        assert!(
+            indices.windows(2).all(|pair| pair[0] < pair[1]),
+            "removal indices should be sorted and unique"
+        );
+        let (Some(&first), Some(&last)) = (indices.first(), indices.last()) else {
+            return MyVec::new_in(self.allocator.clone());
+        };
+        assert!(
+            last < self.length,
+            "removal index (is {last}) should be < len (is {})",
+            self.length
+        );
+
+        // NOTE: Everything that can panic happens before the elements are moved around.
+        let mut removed: MyVec<T, A> =
+            MyVec::with_capacity_in(indices.len(), self.allocator.clone());
+        let base = self.pointer.as_ptr();
+        let mut to_remove = indices.iter().peekable();
+        let mut write = first;
+        for read in first..self.length {
             unsafe {
-                pointer.as_ptr().add(self.length).write(element);
+                if to_remove.next_if_eq(&&read).is_some() {
+                    removed
+                        .as_mut_ptr()
+                        .add(removed.length)
+                        .write(base.add(read).read());
+                    removed.length += 1;
+                } else {
+                    ptr::copy_nonoverlapping(base.add(read), base.add(write), 1);
+                    write += 1;
+                }
             }
-            self.pointer = pointer;
-            self.length += 1;
-            self.capacity = new_capacity;
         }
+        self.length = write;
+        removed
     }
 
-    pub fn get(&self, index: usize) -> Option<&T> {
+    /// Like [`MyVec::remove`], but returns `None` instead of panicking when `index >= len`.
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
         if index >= self.length {
             return None;
         }
 
-        Some(unsafe { self.pointer.as_ptr().add(index).as_ref().unwrap() })
+        Some(self.remove(index))
     }
-}
 
-impl<T> Drop for MyVec<T> {
-    fn drop(&mut self) {
+    /// Removes and returns the element at position `index`, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "swap_remove index (is {index}) should be < len (is {})",
+            self.length
+        );
+
         unsafe {
-            // NOTE: We deallocate elements inside the vector.
-            let to_drop = std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.length);
-            std::ptr::drop_in_place(to_drop);
-            // we could have also iterated over the elements and dropped each one one-by-one.
+            let base = self.pointer.as_ptr();
+            let element = base.add(index).read();
+            // NOTE: When `index` is the last element this copies it onto itself, which is fine
+            // because the length is decremented and the slot is no longer owned.
+            ptr::copy(base.add(self.length - 1), base.add(index), 1);
+            self.length -= 1;
+            element
+        }
+    }
 
-            // NOTE: We deallocate part of memory for the vector where the elements were held.
-            let size = std::mem::size_of::<T>() * self.capacity;
-            let align = std::mem::align_of::<T>();
-            let layout = Layout::from_size_align_unchecked(size, align);
-            dealloc(self.pointer.as_ptr() as *mut u8, layout);
+    /// Shortens the vector to `new_len` elements, dropping the rest. Has no effect if `new_len`
+    /// is greater or equal to the current length. Capacity is left untouched.
+    pub fn truncate(&mut self, new_len: usize) {
+        // NOTE: Elements are dropped back-to-front and `length` is decremented before each drop,
+        // so a panicking destructor leaves only still-initialized elements inside the vector.
+        while self.length > new_len {
+            self.length -= 1;
+            unsafe { ptr::drop_in_place(self.pointer.as_ptr().add(self.length)) };
+        }
+    }
+
+    /// Resizes the vector to `new_len`. Grows by filling the new slots with values returned by
+    /// `f`, or truncates when `new_len` is smaller than the length.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.length);
+
+        let base = self.pointer.as_ptr();
+        let mut length = SetLenOnDrop::new(&mut self.length);
+        while length.local_len < new_len {
+            unsafe { base.add(length.local_len).write(f()) };
+            length.local_len += 1;
+        }
+    }
+
+    /// Overwrites every element with values returned by calling `f` repeatedly.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut_slice().fill_with(f);
+    }
+
+    /// Drops all elements, keeping the allocated capacity for reuse.
+    pub fn clear(&mut self) {
+        let elements = ptr::slice_from_raw_parts_mut(self.pointer.as_ptr(), self.length);
+        // NOTE: `length` is reset before dropping, so if a destructor panics we leak the rest
+        // instead of dropping them again in `Drop`.
+        self.length = 0;
+        unsafe { ptr::drop_in_place(elements) };
+    }
+
+    /// Moves all elements of `other` to the end of this vector, leaving `other` empty. The
+    /// capacity of `other` is kept.
+    pub fn append(&mut self, other: &mut Self) {
+        let count = other.length;
+        self.reserve(count);
+
+        unsafe {
+            // NOTE: Two distinct vectors never share a buffer.
+            ptr::copy_nonoverlapping(
+                other.pointer.as_ptr(),
+                self.pointer.as_ptr().add(self.length),
+                count,
+            );
+        }
+        // NOTE: Elements were moved bitwise, so `other` mustn't drop them anymore.
+        other.length = 0;
+        self.length += count;
+    }
+
+    /// Splits the vector in two at `at`. Returns a new vector with elements `[at, len)`, while
+    /// `self` keeps elements `[0, at)` and its capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> MyVec<T, A>
+    where
+        A: Clone,
+    {
+        assert!(
+            at <= self.length,
+            "`at` split index (is {at}) should be <= len (is {})",
+            self.length
+        );
+
+        let count = self.length - at;
+        let mut other = MyVec::with_capacity_in(count, self.allocator.clone());
+        unsafe {
+            ptr::copy_nonoverlapping(self.pointer.as_ptr().add(at), other.pointer.as_ptr(), count);
+        }
+        self.length = at;
+        other.length = count;
+        other
+    }
+
+    /// Removes the elements in `range` from the vector and returns an iterator over them.
+    ///
+    /// Elements that aren't consumed are dropped together with the iterator, and the elements
+    /// after the range are shifted back in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or out of bounds.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let range = slice_range(range, self.length);
+        Drain::new(self, range)
+    }
+
+    /// Replaces the elements in `range` with the elements of `replace_with`, returning an iterator
+    /// over the removed elements. The replacement can be longer or shorter than the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or out of bounds.
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Splice::new(self.drain(range), replace_with.into_iter())
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order. Each element
+    /// is visited once and moved at most once.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Like [`MyVec::retain`], but `f` gets a mutable reference, so kept elements can be updated
+    /// in the same pass.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        /// Closes the gap left by removed elements, also when `f` or a destructor panics.
+        struct Guard<'a, T, A: Allocator> {
+            vec: &'a mut MyVec<T, A>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
+
+        impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                if self.deleted > 0 {
+                    unsafe {
+                        let base = self.vec.pointer.as_ptr();
+                        ptr::copy(
+                            base.add(self.processed),
+                            base.add(self.processed - self.deleted),
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+                self.vec.length = self.original_len - self.deleted;
+            }
+        }
+
+        let original_len = self.length;
+        // NOTE: While elements are being shuffled the vector is considered empty, so nothing
+        // can observe the holes left behind by removed elements.
+        self.length = 0;
+        let base = self.pointer.as_ptr();
+        let mut guard = Guard {
+            vec: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
         };
+
+        while guard.processed != original_len {
+            let current = unsafe { base.add(guard.processed) };
+            if !f(unsafe { &mut *current }) {
+                // NOTE: Counters are bumped first, a panicking destructor won't be run twice.
+                guard.processed += 1;
+                guard.deleted += 1;
+                unsafe { ptr::drop_in_place(current) };
+                continue;
+            }
+            if guard.deleted > 0 {
+                unsafe { ptr::copy_nonoverlapping(current, current.sub(guard.deleted), 1) };
+            }
+            guard.processed += 1;
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::MyVec;
+    /// Returns an iterator that removes and yields every element for which `predicate` returns
+    /// `true`. The remaining elements keep their order.
+    ///
+    /// Elements are only visited as the iterator advances; dropping it early keeps the rest.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, predicate: F) -> ExtractIf<'_, T, F, A> {
+        ExtractIf::new(self, predicate)
+    }
 
-    #[test]
-    fn push_to_vec() {
-        let mut vec: MyVec<usize> = MyVec::new();
-        vec.push(1_usize);
-        vec.push(2);
-        vec.push(3);
-        vec.push(4);
-        vec.push(5);
-        assert_eq!(vec.capacity(), 8);
-        assert_eq!(vec.len(), 5);
+    /// Removes consecutive elements that map to the same key.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
 
-        assert_eq!(vec.get(3), Some(&4));
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`.
+    /// `previous` is the last element that was kept.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        /// Moves the unvisited elements after the kept ones, also when `same_bucket` or a
+        /// destructor panics.
+        struct Guard<'a, T, A: Allocator> {
+            vec: &'a mut MyVec<T, A>,
+            read: usize,
+            write: usize,
+            original_len: usize,
+        }
+
+        impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                let unvisited = self.original_len - self.read;
+                unsafe {
+                    let base = self.vec.pointer.as_ptr();
+                    ptr::copy(base.add(self.read), base.add(self.write), unvisited);
+                }
+                self.vec.length = self.write + unvisited;
+            }
+        }
+
+        let original_len = self.length;
+        if original_len <= 1 {
+            return;
+        }
+
+        // NOTE: Same as in `retain_mut`, the vector is empty while there are holes in it.
+        self.length = 0;
+        let base = self.pointer.as_ptr();
+        let mut guard = Guard {
+            vec: self,
+            read: 1,
+            write: 1,
+            original_len,
+        };
+
+        while guard.read != original_len {
+            unsafe {
+                let current = base.add(guard.read);
+                let previous = base.add(guard.write - 1);
+                if same_bucket(&mut *current, &mut *previous) {
+                    guard.read += 1;
+                    ptr::drop_in_place(current);
+                } else {
+                    if guard.read != guard.write {
+                        ptr::copy_nonoverlapping(current, base.add(guard.write), 1);
+                    }
+                    guard.read += 1;
+                    guard.write += 1;
+                }
+            }
+        }
     }
 
-    #[derive(Debug, PartialEq)]
-    struct A(usize);
+    /// Consumes the vector and leaks its buffer, returning a mutable slice over the elements that
+    /// lives as long as the caller wants. Spare capacity is leaked as well.
+    pub fn leak<'a>(self) -> &'a mut [T]
+    where
+        A: 'a,
+    {
+        let vec = ManuallyDrop::new(self);
+        unsafe { std::slice::from_raw_parts_mut(vec.pointer.as_ptr(), vec.length) }
+    }
 
-    impl Drop for A {
-        fn drop(&mut self) {
-            println!("Dropped");
+    /// Pins the buffer until the returned guard is dropped. Through the guard, operations that
+    /// would reallocate return an error instead, so pointers into the buffer stay valid.
+    pub fn freeze(&mut self) -> FixedCapacity<'_, T, A> {
+        FixedCapacity::new(self)
+    }
+
+    /// Reserves space for at least `additional` more elements. The capacity is at least doubled
+    /// when it needs to grow, so repeated pushes are amortized O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows or the allocation fails.
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional));
+    }
+
+    /// Like [`MyVec::reserve`], but returns an error instead of panicking. The vector is left
+    /// untouched on failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .length
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if required <= self.capacity {
+            return Ok(());
         }
+
+        // NOTE: Empty vector starts with space for 4 elements.
+        let new_capacity = self.capacity.saturating_mul(2).max(required).max(4);
+        self.try_set_capacity(new_capacity)
     }
 
-    #[test]
-    fn heap_dealloc() {
-        let mut vec = MyVec::new();
-        vec.push(A(1));
-        vec.push(A(2));
-        vec.push(A(3));
+    /// Reserves space for exactly `additional` more elements, without the over-allocation done
+    /// by [`MyVec::reserve`]. Does nothing if the capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows or the allocation fails.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve_exact(additional));
+    }
 
-        assert_eq!(vec.get(0), Some(&A(1)));
-        assert_eq!(vec.get(1), Some(&A(2)));
-        assert_eq!(vec.get(2), Some(&A(3)));
-        assert_eq!(vec.get(3), None);
+    /// Like [`MyVec::reserve_exact`], but returns an error instead of panicking. The vector is
+    /// left untouched on failure.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .length
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        self.try_set_capacity(required)
+    }
+
+    /// Shrinks the capacity to match the length. Empty vector gives up its allocation entirely.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.length {
+            self.set_capacity(self.length);
+        }
+    }
+
+    /// Shrinks the capacity down to `min_capacity`, but never below the length. Does nothing if
+    /// the capacity is already smaller than `min_capacity`.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let new_capacity = self.length.max(min_capacity);
+        if self.capacity > new_capacity {
+            self.set_capacity(new_capacity);
+        }
+    }
+
+    /// Reallocates the buffer so it holds exactly `new_capacity` elements.
+    fn set_capacity(&mut self, new_capacity: usize) {
+        handle_reserve(self.try_set_capacity(new_capacity));
+    }
+
+    /// Reallocates the buffer so it holds exactly `new_capacity` elements. On failure the vector
+    /// keeps its old buffer.
+    fn try_set_capacity(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+        // NOTE: Zero sized elements always fit, the capacity stays at maximum.
+        if Self::IS_ZST {
+            return Ok(());
+        }
+        debug_assert!(new_capacity >= self.length);
+
+        // NOTE: After this point we know that type `T` has to have a size in memory.
+        if new_capacity == 0 {
+            // NOTE: Nothing is stored, so we give the memory back and go back to dangling.
+            if self.capacity != 0 {
+                unsafe {
+                    self.allocator
+                        .deallocate(self.pointer.cast(), self.current_layout())
+                };
+            }
+            self.pointer = NonNull::dangling();
+            self.capacity = 0;
+            return Ok(());
+        }
+
+        // `Layout::array` checks that the size doesn't overflow `isize`.
+        let new_layout =
+            Layout::array::<T>(new_capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        let pointer = if self.capacity == 0 {
+            self.allocator.allocate(new_layout)
+        } else if new_capacity > self.capacity {
+            unsafe {
+                self.allocator
+                    .grow(self.pointer.cast(), self.current_layout(), new_layout)
+            }
+        } else {
+            unsafe {
+                self.allocator
+                    .shrink(self.pointer.cast(), self.current_layout(), new_layout)
+            }
+        };
+        // NOTE: We can bail out here because old `length`, `capacity` and `pointer` are still valid.
+        self.pointer = pointer
+            .ok_or(TryReserveError::AllocError { layout: new_layout })?
+            .cast();
+        self.capacity = new_capacity;
+        Ok(())
+    }
+
+    /// Returns the layout the buffer was allocated with.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be allocated, `T` isn't zero sized and the capacity isn't zero.
+    unsafe fn current_layout(&self) -> Layout {
+        let size = std::mem::size_of::<T>() * self.capacity;
+        unsafe { Layout::from_size_align_unchecked(size, std::mem::align_of::<T>()) }
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+
+        // NOTE: We shrink `length` first so the element we read out is no longer owned by the
+        // vector and won't be dropped a second time in `Drop`.
+        self.length -= 1;
+        Some(unsafe { self.pointer.as_ptr().add(self.length).read() })
+    }
+
+    /// Returns a raw pointer to the buffer. When nothing is allocated the pointer is dangling,
+    /// but still non-null and aligned.
+    pub fn as_ptr(&self) -> *const T {
+        self.pointer.as_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the buffer. When nothing is allocated the pointer is
+    /// dangling, but still non-null and aligned.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.pointer.as_ptr()
+    }
+
+    /// Returns the initialized elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // NOTE: `pointer` is non-null and aligned even when dangling, which is all
+        // `from_raw_parts` needs for an empty slice.
+        unsafe { std::slice::from_raw_parts(self.pointer.as_ptr(), self.length) }
+    }
+
+    /// Returns the initialized elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.length) }
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns the spare capacity as a slice of uninitialized elements. Once filled, claim the
+    /// elements with [`MyVec::set_len`].
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.pointer.as_ptr().add(self.length) as *mut MaybeUninit<T>,
+                self.capacity - self.length,
+            )
+        }
+    }
+
+    /// Returns the initialized elements and the spare capacity at the same time.
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let base = self.pointer.as_ptr();
+        // NOTE: Both slices come from the same buffer but cover disjoint parts of it.
+        unsafe {
+            let initialized = std::slice::from_raw_parts_mut(base, self.length);
+            let spare = std::slice::from_raw_parts_mut(
+                base.add(self.length) as *mut MaybeUninit<T>,
+                self.capacity - self.length,
+            );
+            (initialized, spare)
+        }
+    }
+
+    /// Removes and returns the last element if `predicate` returns `true` for it, otherwise
+    /// leaves the vector unchanged and returns `None`.
+    pub fn pop_if<F: FnOnce(&mut T) -> bool>(&mut self, predicate: F) -> Option<T> {
+        let last = self.get_mut(self.length.checked_sub(1)?)?;
+        if predicate(last) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
+
+        Some(unsafe { self.pointer.as_ptr().add(index).as_ref().unwrap() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length {
+            return None;
+        }
+
+        Some(unsafe { self.pointer.as_ptr().add(index).as_mut().unwrap() })
+    }
+
+    /// Returns a reference to the element at `index` without bounds checking. Debug builds still
+    /// assert that `index` is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than [`MyVec::len`].
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        debug_assert!(
+            index < self.length,
+            "index (is {index}) should be < len (is {})",
+            self.length
+        );
+        unsafe { &*self.pointer.as_ptr().add(index) }
+    }
+
+    /// Returns a mutable reference to the element at `index` without bounds checking. Debug
+    /// builds still assert that `index` is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than [`MyVec::len`].
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(
+            index < self.length,
+            "index (is {index}) should be < len (is {})",
+            self.length
+        );
+        unsafe { &mut *self.pointer.as_ptr().add(index) }
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.length.checked_sub(1)?)
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.length.checked_sub(1)?)
+    }
+
+    /// Returns the first element and the rest, or `None` if the vector is empty.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_first()
+    }
+
+    /// Returns the first element and the rest mutably, or `None` if the vector is empty.
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+        self.as_mut_slice().split_first_mut()
+    }
+
+    /// Returns the last element and the rest, or `None` if the vector is empty.
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_last()
+    }
+
+    /// Returns the last element and the rest mutably, or `None` if the vector is empty.
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+        self.as_mut_slice().split_last_mut()
+    }
+
+    /// Returns mutable references to several elements at once, or `None` if any index is out of
+    /// bounds or appears more than once.
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.length || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // NOTE: Indices are in bounds and pairwise distinct, so the references never alias.
+        let base = self.pointer.as_ptr();
+        Some(indices.map(|index| unsafe { &mut *base.add(index) }))
+    }
+
+    /// Puts `value` at position `index` and returns the element that was there before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        let length = self.length;
+        match self.get_mut(index) {
+            Some(slot) => std::mem::replace(slot, value),
+            None => panic!("replace index (is {index}) should be < len (is {length})"),
+        }
+    }
+
+    /// Like [`MyVec::replace`], but returns `None` instead of panicking when `index` is out of
+    /// bounds. `value` is dropped in that case.
+    pub fn try_replace(&mut self, index: usize, value: T) -> Option<T> {
+        Some(std::mem::replace(self.get_mut(index)?, value))
+    }
+
+    /// Swaps every element with the element at the same position in `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different length.
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.as_mut_slice().swap_with_slice(other);
+    }
+
+    /// Reverses the order of elements in place, swapping them pairwise from both ends.
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Rotates the vector in place so the first `mid` elements move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.length,
+            "rotation (is {mid}) should be <= len (is {})",
+            self.length
+        );
+
+        // NOTE: Triple reversal, `[a, b]` becomes `[b, a]` after reversing both parts and the whole.
+        let elements = self.as_mut_slice();
+        elements[..mid].reverse();
+        elements[mid..].reverse();
+        elements.reverse();
+    }
+
+    /// Rotates the vector in place so the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > len`.
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.length,
+            "rotation (is {k}) should be <= len (is {})",
+            self.length
+        );
+
+        self.rotate_left(self.length - k);
+    }
+
+    /// Returns the index of the first element for which `predicate` returns `true`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.as_slice().iter().position(predicate)
+    }
+
+    /// Swaps the elements at positions `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.length && b < self.length,
+            "swap indices (are {a} and {b}) should be < len (is {})",
+            self.length
+        );
+
+        // NOTE: `ptr::swap` allows both pointers to be the same.
+        unsafe {
+            let base = self.pointer.as_ptr();
+            ptr::swap(base.add(a), base.add(b));
+        }
+    }
+}
+
+impl<T, A: Allocator> MyVec<MaybeUninit<T>, A> {
+    /// Converts the vector into a vector of initialized elements, reusing the allocation.
+    ///
+    /// # Safety
+    ///
+    /// Every element in `0..len` must be initialized.
+    pub unsafe fn assume_init(self) -> MyVec<T, A> {
+        let (pointer, length, capacity, allocator) = self.into_raw_parts_with_alloc();
+        // NOTE: `MaybeUninit<T>` has the same size and alignment as `T`, so the buffer layout is
+        // unchanged.
+        unsafe { MyVec::from_raw_parts_in(pointer.cast::<T>(), length, capacity, allocator) }
+    }
+}
+
+impl<T: Clone> MyVec<T> {
+    /// Creates a vector of `n` clones of `value`, allocating once up front.
+    ///
+    /// Every slot is written, the generic `T` can't be checked for zero bits here. Use
+    /// `my_vec![value; n]` with an [`IsZero`] element to get zeroed memory from the allocator.
+    pub fn from_elem(value: T, n: usize) -> Self {
+        let mut vec = Self::with_capacity(n);
+        if n != 0 {
+            vec.resize(n, value);
+        }
+        vec
+    }
+}
+
+impl<T: Clone, A: Allocator> MyVec<T, A> {
+    /// Resizes the vector to `new_len`. Grows by cloning `value` into the new slots, or
+    /// truncates when `new_len` is smaller than the length.
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        let additional = new_len - self.length;
+        self.reserve(additional);
+
+        let base = self.pointer.as_ptr();
+        let mut length = SetLenOnDrop::new(&mut self.length);
+        for _ in 1..additional {
+            unsafe { base.add(length.local_len).write(value.clone()) };
+            length.local_len += 1;
+        }
+        // NOTE: The last slot takes `value` itself, saving one clone.
+        unsafe { base.add(length.local_len).write(value) };
+        length.local_len += 1;
+    }
+
+    /// Clones and appends all elements of `other`. Space is reserved once up front.
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        handle_reserve(self.try_extend_from_slice(other));
+    }
+
+    /// Like [`MyVec::extend_from_slice`], but returns an error instead of panicking when the
+    /// vector can't grow. The vector is left untouched on failure.
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+
+        let base = self.pointer.as_ptr();
+        let mut length = SetLenOnDrop::new(&mut self.length);
+        for element in other {
+            // NOTE: Length is only published when the guard drops, which lets the compiler lower
+            // this loop to `copy_nonoverlapping` for `Copy` element types.
+            unsafe { base.add(length.local_len).write(element.clone()) };
+            length.local_len += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns a new vector with the contents repeated `n` times. The result is built by doubling
+    /// what was already copied, allocating once from a clone of the allocator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length overflows.
+    pub fn repeat(&self, n: usize) -> MyVec<T, A>
+    where
+        A: Clone,
+    {
+        let total = self.length.checked_mul(n).expect("capacity overflow");
+        let mut repeated = MyVec::with_capacity_in(total, self.allocator.clone());
+        if total == 0 {
+            return repeated;
+        }
+
+        repeated.extend_from_slice(self.as_slice());
+        while repeated.length <= total / 2 {
+            repeated.extend_from_within(..);
+        }
+        // NOTE: Whatever is left is shorter than the current contents.
+        let rest = total - repeated.length;
+        repeated.extend_from_within(..rest);
+        repeated
+    }
+
+    /// Overwrites every element with a clone of `value`.
+    pub fn fill(&mut self, value: T) {
+        // NOTE: Slice `fill` is specialized in std, for byte sized `Copy` types it's a `memset`.
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Overwrites every element with a clone of the element at the same position in `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` has a different length.
+    pub fn clone_from_slice(&mut self, src: &[T]) {
+        self.as_mut_slice().clone_from_slice(src);
+    }
+
+    /// Clones all elements of `other` into position `index`, shifting the elements after it to
+    /// the right. Space is reserved once and the tail is moved a single time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_slice(&mut self, index: usize, other: &[T]) {
+        self.insert_from_iter(index, other.iter().cloned());
+    }
+
+    /// Clones the elements in range `src` and appends them to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or out of bounds.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R) {
+        let range = slice_range(src, self.length);
+        self.reserve(range.len());
+
+        // NOTE: Pointer is read after `reserve`, which may have moved the buffer.
+        let base = self.pointer.as_ptr();
+        let mut length = SetLenOnDrop::new(&mut self.length);
+        for index in range {
+            // NOTE: Source elements are below the old length and destination slots are in the
+            // spare capacity, so they never alias.
+            unsafe {
+                let element = (*base.add(index)).clone();
+                base.add(length.local_len).write(element);
+            }
+            length.local_len += 1;
+        }
+    }
+}
+
+impl<T: Copy, A: Allocator> MyVec<T, A> {
+    /// Overwrites every element with the contents of `src` using a single `memcpy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` has a different length.
+    pub fn copy_from_slice(&mut self, src: &[T]) {
+        self.as_mut_slice().copy_from_slice(src);
+    }
+
+    /// Copies the elements in range `src` to the position starting at `dest`. The two regions
+    /// may overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either region is out of bounds.
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let Range { start, end } = slice_range(src, self.length);
+        let count = end - start;
+        assert!(dest <= self.length - count, "dest is out of bounds");
+
+        unsafe {
+            let base = self.pointer.as_ptr();
+            ptr::copy(base.add(start), base.add(dest), count);
+        }
+    }
+}
+
+impl MyVec<u8> {
+    /// Creates a vector of `n` zero bytes. Memory comes zeroed straight from the allocator, which
+    /// is cheaper than `from_elem(0, n)` writing every byte.
+    pub fn zeroed(n: usize) -> Self {
+        Self::from_zeroed(n)
+    }
+}
+
+impl<T: PartialEq, A: Allocator> MyVec<T, A> {
+    /// Removes consecutive repeated elements. If the vector is sorted, this removes all
+    /// duplicates.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Returns `true` if the vector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool {
+        // NOTE: Slice `contains` is specialized in std, for bytes it's a `memchr` search.
+        self.as_slice().contains(x)
+    }
+
+    /// Returns `true` if `needle` is a prefix of the vector.
+    pub fn starts_with(&self, needle: &[T]) -> bool {
+        self.as_slice().starts_with(needle)
+    }
+
+    /// Returns `true` if `needle` is a suffix of the vector.
+    pub fn ends_with(&self, needle: &[T]) -> bool {
+        self.as_slice().ends_with(needle)
+    }
+
+    /// Returns the elements after `prefix`, or `None` if the vector doesn't start with it.
+    pub fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]> {
+        self.as_slice().strip_prefix(prefix)
+    }
+
+    /// Returns the elements before `suffix`, or `None` if the vector doesn't end with it.
+    pub fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]> {
+        self.as_slice().strip_suffix(suffix)
+    }
+
+    /// Removes the first element equal to `x` and returns it, shifting the elements after it to
+    /// the left.
+    pub fn remove_item(&mut self, x: &T) -> Option<T> {
+        let index = self.position(|element| element == x)?;
+        Some(self.remove(index))
+    }
+
+    /// Removes the first element equal to `x` and returns it, replacing it with the last element.
+    pub fn swap_remove_item(&mut self, x: &T) -> Option<T> {
+        let index = self.position(|element| element == x)?;
+        Some(self.swap_remove(index))
+    }
+}
+
+/// Panics with the reservation error, used by the infallible wrappers around the `try_` methods.
+fn handle_reserve(result: Result<(), TryReserveError>) {
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
+/// Converts `range` to a `Range` within `0..len`.
+///
+/// # Panics
+///
+/// Panics if the range is decreasing or out of bounds.
+fn slice_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for slice of length {len}"
+    );
+    start..end
+}
+
+/// Writes the locally tracked length back into the vector when dropped, so if `clone` or another
+/// user callback panics while filling spare capacity, every element written so far is kept.
+struct SetLenOnDrop<'a> {
+    length: &'a mut usize,
+    local_len: usize,
+}
+
+impl<'a> SetLenOnDrop<'a> {
+    fn new(length: &'a mut usize) -> Self {
+        let local_len = *length;
+        Self { length, local_len }
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    fn drop(&mut self) {
+        *self.length = self.local_len;
+    }
+}
+
+// NOTE: With the `nightly` feature the drop checker is told that borrows inside `T` may already
+// be dangling when the vector drops, like for std `Vec`. Dropping the elements still counts as
+// using `T` because of `marker`.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for MyVec<T, A> {
+    fn drop(&mut self) {
+        unsafe { self.drop_and_dealloc() }
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, A: Allocator> Drop for MyVec<T, A> {
+    fn drop(&mut self) {
+        unsafe { self.drop_and_dealloc() }
+    }
+}
+
+impl<T, A: Allocator> MyVec<T, A> {
+    /// Drops the elements and frees the buffer, shared by both `Drop` impls.
+    ///
+    /// # Safety
+    ///
+    /// The vector mustn't be used afterwards.
+    unsafe fn drop_and_dealloc(&mut self) {
+        unsafe {
+            // NOTE: We deallocate elements inside the vector.
+            let to_drop = std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.length);
+            std::ptr::drop_in_place(to_drop);
+            // we could have also iterated over the elements and dropped each one one-by-one.
+
+            // NOTE: With the `zeroize` feature the whole buffer is wiped before it's freed.
+            #[cfg(feature = "zeroize")]
+            {
+                self.length = 0;
+                ::zeroize::Zeroize::zeroize(self.spare_capacity_mut());
+            }
+
+            // NOTE: We deallocate part of memory for the vector where the elements were held.
+            // Vector that never allocated holds a dangling pointer which mustn't be deallocated.
+            if !Self::IS_ZST && self.capacity != 0 {
+                let layout = self.current_layout();
+                self.allocator.deallocate(self.pointer.cast(), layout);
+            }
+        }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for MyVec<T, A> {
+    /// The clone gets its memory from a clone of the allocator.
+    fn clone(&self) -> Self {
+        let mut vec = MyVec::with_capacity_in(self.length, self.allocator.clone());
+        vec.extend_from_slice(self.as_slice());
+        vec
+    }
+
+    /// Reuses the existing allocation, it only grows when `source` doesn't fit.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.length);
+
+        // NOTE: Shared prefix is cloned in place, so `T::clone_from` can reuse its resources too.
+        let (init, tail) = source.split_at(self.length);
+        self.as_mut_slice().clone_from_slice(init);
+        self.extend_from_slice(tail);
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for MyVec<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+/// Equality compares elements like slices do, so the container type on either side doesn't matter.
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+    };
+}
+
+impl_slice_eq!([A: Allocator, B: Allocator] MyVec<T, A>, MyVec<U, B>);
+impl_slice_eq!([A: Allocator] MyVec<T, A>, [U]);
+impl_slice_eq!([A: Allocator] MyVec<T, A>, &[U]);
+impl_slice_eq!([A: Allocator] MyVec<T, A>, &mut [U]);
+impl_slice_eq!([A: Allocator] MyVec<T, A>, Vec<U>);
+impl_slice_eq!([A: Allocator, const N: usize] MyVec<T, A>, [U; N]);
+impl_slice_eq!([A: Allocator, const N: usize] MyVec<T, A>, &[U; N]);
+impl_slice_eq!([B: Allocator] [T], MyVec<U, B>);
+impl_slice_eq!([B: Allocator] &[T], MyVec<U, B>);
+impl_slice_eq!([B: Allocator] &mut [T], MyVec<U, B>);
+impl_slice_eq!([B: Allocator] Vec<T>, MyVec<U, B>);
+impl_slice_eq!([B: Allocator, const N: usize] [T; N], MyVec<U, B>);
+
+impl<T: Eq, A: Allocator> Eq for MyVec<T, A> {}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for MyVec<T, A> {
+    /// Compares lexicographically, element by element.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for MyVec<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for MyVec<T, A> {
+    /// Hashes the same as a slice, the length is written before the elements.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self.as_slice(), state);
+    }
+}
+
+impl<T> Default for MyVec<T> {
+    /// Creates an empty vector without allocating.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> Deref for MyVec<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> DerefMut for MyVec<T, A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Allocator> AsRef<[T]> for MyVec<T, A> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> AsMut<[T]> for MyVec<T, A> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Allocator> AsRef<MyVec<T, A>> for MyVec<T, A> {
+    fn as_ref(&self) -> &MyVec<T, A> {
+        self
+    }
+}
+
+impl<T, A: Allocator> AsMut<MyVec<T, A>> for MyVec<T, A> {
+    fn as_mut(&mut self) -> &mut MyVec<T, A> {
+        self
+    }
+}
+
+// NOTE: `Eq`, `Ord` and `Hash` all match the slice impls, as `Borrow` requires.
+impl<T, A: Allocator> Borrow<[T]> for MyVec<T, A> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> BorrowMut<[T]> for MyVec<T, A> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for MyVec<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.length
+            ),
+        }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for MyVec<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let length = self.length;
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {length} but the index is {index}"),
+        }
+    }
+}
+
+/// Range indexing returns sub-slices, panicking on bad ranges just like slices do.
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {
+        $(
+            impl<T, A: Allocator> Index<$range> for MyVec<T, A> {
+                type Output = [T];
+
+                fn index(&self, range: $range) -> &[T] {
+                    &self.as_slice()[range]
+                }
+            }
+
+            impl<T, A: Allocator> IndexMut<$range> for MyVec<T, A> {
+                fn index_mut(&mut self, range: $range) -> &mut [T] {
+                    &mut self.as_mut_slice()[range]
+                }
+            }
+        )*
+    };
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeTo<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>
+);
+
+impl<T, A: Allocator> Add for MyVec<T, A> {
+    type Output = MyVec<T, A>;
+
+    /// Concatenates the vectors. Elements of `rhs` are moved into the buffer of `self`, which
+    /// only grows when it doesn't have room for them.
+    fn add(mut self, mut rhs: MyVec<T, A>) -> MyVec<T, A> {
+        self.append(&mut rhs);
+        self
+    }
+}
+
+impl<T: Clone, A: Allocator> Add<&[T]> for MyVec<T, A> {
+    type Output = MyVec<T, A>;
+
+    /// Clones the elements of `rhs` to the end of `self`, reusing its buffer when it has room.
+    fn add(mut self, rhs: &[T]) -> MyVec<T, A> {
+        self.extend_from_slice(rhs);
+        self
+    }
+}
+
+impl<T, A: Allocator> AddAssign for MyVec<T, A> {
+    fn add_assign(&mut self, mut rhs: MyVec<T, A>) {
+        self.append(&mut rhs);
+    }
+}
+
+impl<T: Clone, A: Allocator> AddAssign<&[T]> for MyVec<T, A> {
+    fn add_assign(&mut self, rhs: &[T]) {
+        self.extend_from_slice(rhs);
+    }
+}
+
+impl<T> FromIterator<T> for MyVec<T> {
+    /// Collects the elements, preallocating for the lower bound of the size hint. If the source
+    /// iterator panics, the collected elements are dropped together with the vector.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        let mut vec = MyVec::with_capacity(lower_bound);
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for MyVec<T, A> {
+    /// Appends the elements, reserving for the lower bound of the size hint up front. When the
+    /// hint turns out too small, growth is amortized and again uses the remaining hint.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
+
+        while let Some(element) = iter.next() {
+            if self.length == self.capacity {
+                let (lower_bound, _) = iter.size_hint();
+                self.reserve(lower_bound.saturating_add(1));
+            }
+            unsafe { self.pointer.as_ptr().add(self.length).write(element) };
+            self.length += 1;
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for MyVec<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a MyVec<T, A> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut MyVec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone> From<&[T]> for MyVec<T> {
+    /// Clones the elements into a vector of exactly matching capacity.
+    fn from(slice: &[T]) -> Self {
+        let mut vec = MyVec::with_capacity(slice.len());
+        vec.extend_from_slice(slice);
+        vec
+    }
+}
+
+impl<T: Clone> From<&mut [T]> for MyVec<T> {
+    fn from(slice: &mut [T]) -> Self {
+        MyVec::from(&*slice)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for MyVec<T> {
+    /// Moves the elements out of the array with a single `memcpy`.
+    fn from(array: [T; N]) -> Self {
+        let mut vec = MyVec::with_capacity(N);
+        // NOTE: Array won't drop the elements anymore, the vector owns them after the copy.
+        let array = ManuallyDrop::new(array);
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), vec.pointer.as_ptr(), N);
+            vec.set_len(N);
+        }
+        vec
+    }
+}
+
+impl<T> From<Vec<T>> for MyVec<T> {
+    /// Takes over the buffer of `vec` without copying. Both use the global allocator with
+    /// `Layout::array::<T>(capacity)`, so the vector can free it later. Code that produces std
+    /// vectors, like the strategies and archives of the optional integrations, builds on this.
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+        unsafe { MyVec::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity()) }
+    }
+}
+
+impl<T> From<MyVec<T>> for Vec<T> {
+    /// Hands the buffer over to a std `Vec` without copying, so APIs built on std vectors, like
+    /// rayon's parallel iterator, take the elements for free.
+    fn from(vec: MyVec<T>) -> Self {
+        let (pointer, length, capacity) = vec.into_raw_parts();
+        // NOTE: Empty vector holds a dangling pointer, which `Vec` never deallocates either.
+        unsafe { Vec::from_raw_parts(pointer, length, capacity) }
+    }
+}
+
+impl<T> From<MyVec<T>> for Box<[T]> {
+    /// Spare capacity is released first, see [`MyVec::into_boxed_slice`].
+    fn from(vec: MyVec<T>) -> Self {
+        vec.into_boxed_slice()
+    }
+}
+
+impl<T> From<MyVec<T>> for Rc<[T]> {
+    /// Elements are moved into a new allocation next to the reference counts with one `memcpy`,
+    /// the old buffer is freed without dropping them.
+    fn from(vec: MyVec<T>) -> Self {
+        Rc::from(Vec::from(vec))
+    }
+}
+
+impl<T> From<MyVec<T>> for Arc<[T]> {
+    /// Elements are moved the same way as for [`Rc<[T]>`](Rc).
+    fn from(vec: MyVec<T>) -> Self {
+        Arc::from(Vec::from(vec))
+    }
+}
+
+impl From<String> for MyVec<u8> {
+    /// Takes over the buffer of the string without copying.
+    fn from(string: String) -> Self {
+        MyVec::from(string.into_bytes())
+    }
+}
+
+impl From<&str> for MyVec<u8> {
+    /// Copies the bytes of the string into a vector of exactly matching capacity.
+    fn from(string: &str) -> Self {
+        MyVec::from(string.as_bytes())
+    }
+}
+
+impl<A: Allocator> io::Write for MyVec<u8, A> {
+    /// Appends all of `buf`, growing the vector as needed. Failing to grow is reported as
+    /// [`io::ErrorKind::OutOfMemory`] instead of panicking.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        let total = bufs.iter().map(|buf| buf.len()).sum();
+        self.try_reserve(total).map_err(out_of_memory)?;
+        for buf in bufs {
+            self.extend_from_slice(buf);
+        }
+        Ok(total)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.try_extend_from_slice(buf).map_err(out_of_memory)
+    }
+
+    /// Bytes are in the vector as soon as they're written, there's nothing to flush.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn out_of_memory(err: TryReserveError) -> io::Error {
+    io::Error::new(io::ErrorKind::OutOfMemory, err)
+}
+
+impl<T, A: Allocator, const N: usize> TryFrom<MyVec<T, A>> for [T; N] {
+    type Error = MyVec<T, A>;
+
+    /// Moves the elements into an array if the vector has exactly `N` of them, otherwise the
+    /// vector is returned back unchanged.
+    fn try_from(mut vec: MyVec<T, A>) -> Result<Self, Self::Error> {
+        if vec.length != N {
+            return Err(vec);
+        }
+
+        // NOTE: Vector gives up ownership of the elements but still frees the buffer when dropped.
+        vec.length = 0;
+        Ok(unsafe { vec.pointer.as_ptr().cast::<[T; N]>().read() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::{MyVec, TryReserveError};
+
+    #[test]
+    fn push_to_vec() {
+        let mut vec: MyVec<usize> = MyVec::new();
+        vec.push(1_usize);
+        vec.push(2);
+        vec.push(3);
+        vec.push(4);
+        vec.push(5);
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(vec.len(), 5);
+
+        assert_eq!(vec.get(3), Some(&4));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct A(usize);
+
+    impl Drop for A {
+        fn drop(&mut self) {
+            println!("Dropped");
+        }
+    }
+
+    #[test]
+    fn heap_dealloc() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+        vec.push(A(3));
+
+        assert_eq!(vec.get(0), Some(&A(1)));
+        assert_eq!(vec.get(1), Some(&A(2)));
+        assert_eq!(vec.get(2), Some(&A(3)));
+        assert_eq!(vec.get(3), None);
+    }
+
+    #[test]
+    fn pop_from_vec() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+
+        assert_eq!(vec.pop(), Some(A(2)));
+        assert_eq!(vec.pop(), Some(A(1)));
+        assert_eq!(vec.pop(), None);
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 4);
+    }
+
+    #[test]
+    fn insert_into_vec() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        vec.push(3);
+        vec.insert(1, 2);
+        vec.insert(0, 0);
+        vec.insert(4, 4);
+
+        assert_eq!(vec.len(), 5);
+        assert_eq!(vec.capacity(), 8);
+        for i in 0..5 {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        vec.insert(2, 2);
+    }
+
+    #[test]
+    fn remove_from_vec() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+        vec.push(A(3));
+
+        assert_eq!(vec.remove(0), A(1));
+        assert_eq!(vec.remove(1), A(3));
+        assert_eq!(vec.len(), 1);
+        assert_eq!(vec.get(0), Some(&A(2)));
+    }
+
+    #[test]
+    fn swap_remove_from_vec() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+        vec.push(A(3));
+
+        assert_eq!(vec.swap_remove(0), A(1));
+        assert_eq!(vec.get(0), Some(&A(3)));
+        assert_eq!(vec.swap_remove(1), A(2));
+        assert_eq!(vec.len(), 1);
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(A(i));
+        }
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(vec.get(0), None);
+
+        vec.push(A(7));
+        assert_eq!(vec.get(0), Some(&A(7)));
+    }
+
+    #[test]
+    fn truncate_vec() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(A(i));
+        }
+
+        vec.truncate(7);
+        assert_eq!(vec.len(), 5);
+
+        vec.truncate(2);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(vec.get(1), Some(&A(1)));
+        assert_eq!(vec.get(2), None);
+    }
+
+    #[test]
+    fn with_capacity_allocates_once() {
+        let mut vec = MyVec::with_capacity(10);
+        assert_eq!(vec.capacity(), 10);
+        assert!(vec.is_empty());
+
+        for i in 0..10 {
+            vec.push(A(i));
+        }
+        assert_eq!(vec.capacity(), 10);
+
+        vec.push(A(10));
+        assert_eq!(vec.capacity(), 20);
+        assert_eq!(vec.get(10), Some(&A(10)));
+    }
+
+    #[test]
+    fn with_zero_capacity() {
+        let mut vec: MyVec<A> = MyVec::with_capacity(0);
+        assert_eq!(vec.capacity(), 0);
+
+        vec.push(A(1));
+        assert_eq!(vec.capacity(), 4);
+    }
+
+    #[test]
+    fn reserve_grows_amortized() {
+        let mut vec: MyVec<usize> = MyVec::new();
+        vec.reserve(3);
+        assert_eq!(vec.capacity(), 4);
+
+        vec.push(1);
+        vec.reserve(10);
+        assert_eq!(vec.capacity(), 11);
+
+        vec.reserve(10);
+        assert_eq!(vec.capacity(), 11);
+
+        vec.reserve(11);
+        assert_eq!(vec.capacity(), 22);
+        assert_eq!(vec.get(0), Some(&1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reserve_overflow() {
+        let mut vec: MyVec<usize> = MyVec::new();
+        vec.push(1);
+        vec.reserve(usize::MAX);
+    }
+
+    #[test]
+    fn reserve_exact_capacity() {
+        let mut vec: MyVec<usize> = MyVec::new();
+        vec.reserve_exact(3);
+        assert_eq!(vec.capacity(), 3);
+
+        vec.push(1);
+        vec.reserve_exact(10);
+        assert_eq!(vec.capacity(), 11);
+
+        vec.reserve_exact(2);
+        assert_eq!(vec.capacity(), 11);
+    }
+
+    #[test]
+    fn shrink_to_fit_vec() {
+        let mut vec = MyVec::with_capacity(10);
+        vec.push(A(1));
+        vec.push(A(2));
+
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 2);
+        assert_eq!(vec.get(1), Some(&A(2)));
+
+        vec.clear();
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 0);
+
+        vec.push(A(3));
+        assert_eq!(vec.get(0), Some(&A(3)));
+    }
+
+    #[test]
+    fn shrink_to_vec() {
+        let mut vec: MyVec<usize> = MyVec::with_capacity(10);
+        vec.push(1);
+        vec.push(2);
+
+        vec.shrink_to(5);
+        assert_eq!(vec.capacity(), 5);
+
+        vec.shrink_to(8);
+        assert_eq!(vec.capacity(), 5);
+
+        vec.shrink_to(0);
+        assert_eq!(vec.capacity(), 2);
+        assert_eq!(vec.get(1), Some(&2));
+    }
+
+    #[test]
+    fn get_mut_element() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+
+        vec.get_mut(1).unwrap().0 = 5;
+        assert_eq!(vec.get(1), Some(&A(5)));
+        assert_eq!(vec.get_mut(2), None);
+    }
+
+    #[test]
+    fn slice_views() {
+        let mut vec: MyVec<usize> = MyVec::new();
+        assert_eq!(vec.as_slice(), &[]);
+
+        vec.push(3);
+        vec.push(1);
+        vec.push(2);
+        vec.as_mut_slice().sort();
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn extend_from_slice_clones() {
+        let mut vec = MyVec::new();
+        vec.push(String::from("a"));
+        vec.extend_from_slice(&[String::from("b"), String::from("c")]);
+        assert_eq!(vec.as_slice(), &["a", "b", "c"]);
+
+        vec.extend_from_slice(&[]);
+        assert_eq!(vec.len(), 3);
+    }
+
+    #[test]
+    fn extend_from_within_vec() {
+        let mut vec = MyVec::new();
+        vec.extend_from_slice(&[String::from("a"), String::from("b"), String::from("c")]);
+
+        vec.extend_from_within(1..);
+        assert_eq!(vec.as_slice(), &["a", "b", "c", "b", "c"]);
+
+        vec.extend_from_within(..=0);
+        assert_eq!(vec.as_slice(), &["a", "b", "c", "b", "c", "a"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_from_within_out_of_bounds() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        vec.extend_from_within(0..2);
+    }
+
+    #[test]
+    fn append_moves_elements() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        let mut other = MyVec::with_capacity(6);
+        other.push(A(2));
+        other.push(A(3));
+
+        vec.append(&mut other);
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(3)]);
+        assert!(other.is_empty());
+        assert_eq!(other.capacity(), 6);
+    }
+
+    #[test]
+    fn split_off_tail() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(A(i));
+        }
+
+        let tail = vec.split_off(3);
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(2)]);
+        assert_eq!(tail.as_slice(), &[A(3), A(4)]);
+        assert_eq!(vec.capacity(), 8);
+
+        let empty = vec.split_off(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn retain_matching() {
+        let mut vec = MyVec::new();
+        for i in 0..7 {
+            vec.push(A(i));
+        }
+
+        vec.retain(|element| element.0 % 3 != 0);
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(4), A(5)]);
+    }
+
+    #[test]
+    fn retain_panic_keeps_elements() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(String::from("a") + &i.to_string());
+        }
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.retain(|element| {
+                assert_ne!(element, "a3");
+                element != "a1"
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.as_slice(), &["a0", "a2", "a3", "a4"]);
+    }
+
+    #[test]
+    fn retain_mut_updates_kept() {
+        let mut vec = MyVec::new();
+        for i in 1..5 {
+            vec.push(A(i));
+        }
+
+        vec.retain_mut(|element| {
+            element.0 -= 1;
+            element.0 != 0
+        });
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(3)]);
+    }
+
+    #[test]
+    fn dedup_family() {
+        let mut vec = MyVec::new();
+        for i in [1, 1, 2, 3, 3, 3, 1] {
+            vec.push(A(i));
+        }
+        vec.dedup();
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(3), A(1)]);
+
+        vec.dedup_by_key(|element| element.0 / 2);
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(1)]);
+
+        vec.dedup_by(|_, _| true);
+        assert_eq!(vec.as_slice(), &[A(1)]);
+    }
+
+    #[test]
+    fn resize_vec() {
+        let mut vec = MyVec::new();
+        vec.push(String::from("a"));
+
+        vec.resize(3, String::from("b"));
+        assert_eq!(vec.as_slice(), &["a", "b", "b"]);
+
+        vec.resize(1, String::from("c"));
+        assert_eq!(vec.as_slice(), &["a"]);
+    }
+
+    #[test]
+    fn resize_with_generator() {
+        let mut vec = MyVec::new();
+        let mut next = 0;
+        vec.resize_with(3, || {
+            next += 1;
+            A(next)
+        });
+        assert_eq!(vec.as_slice(), &[A(1), A(2), A(3)]);
+
+        vec.resize_with(1, || unreachable!());
+        assert_eq!(vec.as_slice(), &[A(1)]);
+    }
+
+    #[test]
+    fn swap_elements() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+        vec.push(A(3));
+
+        vec.swap(0, 2);
+        vec.swap(1, 1);
+        assert_eq!(vec.as_slice(), &[A(3), A(2), A(1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_out_of_bounds() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        vec.swap(0, 1);
+    }
+
+    #[test]
+    fn search_helpers() {
+        let mut vec = MyVec::new();
+        vec.extend_from_slice(b"hello");
+
+        assert!(vec.contains(&b'l'));
+        assert!(!vec.contains(&b'z'));
+        assert_eq!(vec.position(|&byte| byte == b'l'), Some(2));
+        assert_eq!(vec.position(|&byte| byte == b'z'), None);
+    }
+
+    #[test]
+    fn into_boxed_slice_shrinks() {
+        let mut vec = MyVec::with_capacity(10);
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+
+        let boxed = vec.into_boxed_slice();
+        assert_eq!(&*boxed, &["a", "b"]);
+
+        let empty: Box<[String]> = MyVec::with_capacity(3).into_boxed_slice();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn leak_returns_slice() {
+        let mut vec = MyVec::with_capacity(2);
+        vec.push(A(1));
+        vec.push(A(2));
+
+        let leaked: &'static mut [A] = vec.leak();
+        leaked[0].0 = 3;
+        assert_eq!(leaked, &[A(3), A(2)]);
+
+        // NOTE: Capacity matches the length, so the leaked buffer can be reclaimed like a box.
+        drop(unsafe { Box::from_raw(leaked) });
+    }
+
+    #[test]
+    fn set_len_claims_written() {
+        let mut vec: MyVec<usize> = MyVec::with_capacity(3);
+        unsafe {
+            for i in 0..3 {
+                vec.as_mut_ptr().add(i).write(i * 10);
+            }
+            vec.set_len(3);
+        }
+        assert_eq!(vec.as_slice(), &[0, 10, 20]);
+
+        unsafe { vec.set_len(1) };
+        assert_eq!(vec.as_slice(), &[0]);
+    }
+
+    #[test]
+    fn raw_parts_round_trip() {
+        let mut vec = MyVec::new();
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+
+        let (pointer, length, capacity) = vec.into_raw_parts();
+        assert_eq!((length, capacity), (2, 4));
+
+        let vec = unsafe { MyVec::from_raw_parts(pointer, length, capacity) };
+        assert_eq!(vec.as_slice(), &["a", "b"]);
+    }
+
+    #[test]
+    fn fill_spare_capacity() {
+        let mut vec: MyVec<usize> = MyVec::with_capacity(4);
+        vec.push(1);
+
+        let spare = vec.spare_capacity_mut();
+        assert_eq!(spare.len(), 3);
+        spare[0].write(2);
+        spare[1].write(3);
+        unsafe { vec.set_len(3) };
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn split_at_spare() {
+        let mut vec: MyVec<usize> = MyVec::with_capacity(4);
+        vec.push(1);
+        vec.push(2);
+
+        let (initialized, spare) = vec.split_at_spare_mut();
+        for (slot, element) in spare.iter_mut().zip(initialized.iter()) {
+            slot.write(element * 10);
+        }
+        initialized[0] = 5;
+        unsafe { vec.set_len(4) };
+        assert_eq!(vec.as_slice(), &[5, 2, 10, 20]);
+    }
+
+    #[test]
+    fn push_within_capacity_never_grows() {
+        let mut vec = MyVec::with_capacity(1);
+        assert_eq!(vec.push_within_capacity(A(1)), Ok(()));
+        assert_eq!(vec.push_within_capacity(A(2)), Err(A(2)));
+        assert_eq!(vec.capacity(), 1);
+
+        let mut empty = MyVec::new();
+        assert_eq!(empty.push_within_capacity(A(3)), Err(A(3)));
+    }
+
+    #[test]
+    fn try_reserve_errors() {
+        let mut vec: MyVec<u64> = MyVec::new();
+        vec.push(1);
+
+        assert_eq!(
+            vec.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(
+            vec.try_reserve_exact(isize::MAX as usize),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(vec.as_slice(), &[1]);
+
+        assert_eq!(vec.try_reserve_exact(10), Ok(()));
+        assert_eq!(vec.capacity(), 11);
+    }
+
+    #[test]
+    fn fallible_mutation() {
+        let mut vec: MyVec<u64> = MyVec::new();
+        assert_eq!(vec.try_push(2), Ok(()));
+        assert_eq!(vec.try_insert(0, 1), Ok(()));
+        assert_eq!(vec.try_extend_from_slice(&[3, 4]), Ok(()));
+        assert_eq!(vec.try_extend([5, 6]), Ok(()));
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        // NOTE: Lower bound of the size hint is too large to ever be reserved.
+        let huge = std::iter::repeat_n(0, usize::MAX);
+        assert_eq!(vec.try_extend(huge), Err(TryReserveError::CapacityOverflow));
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn pop_if_predicate() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+
+        assert_eq!(vec.pop_if(|element| element.0 == 1), None);
+        assert_eq!(vec.pop_if(|element| element.0 == 2), Some(A(2)));
+        assert_eq!(vec.pop_if(|_| true), Some(A(1)));
+        assert_eq!(vec.pop_if(|_| true), None);
+    }
+
+    #[test]
+    fn bulk_insert() {
+        let mut vec = MyVec::new();
+        vec.extend_from_slice(&[String::from("a"), String::from("d")]);
+
+        vec.insert_slice(1, &[String::from("b"), String::from("c")]);
+        assert_eq!(vec.as_slice(), &["a", "b", "c", "d"]);
+
+        vec.insert_from_iter(4, (0..2).map(|i| i.to_string()));
+        vec.insert_from_iter(0, (0..3).filter(|i| i % 2 == 0).map(|i| i.to_string()));
+        assert_eq!(vec.as_slice(), &["0", "2", "a", "b", "c", "d", "0", "1"]);
+    }
+
+    #[test]
+    fn replace_element() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+
+        assert_eq!(vec.replace(1, A(3)), A(2));
+        assert_eq!(vec.try_replace(0, A(4)), Some(A(1)));
+        assert_eq!(vec.try_replace(2, A(5)), None);
+        assert_eq!(vec.as_slice(), &[A(4), A(3)]);
+    }
+
+    #[test]
+    fn end_accessors() {
+        let mut vec = MyVec::new();
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last_mut(), None);
+
+        vec.push(A(1));
+        vec.push(A(2));
+        vec.first_mut().unwrap().0 = 3;
+        vec.last_mut().unwrap().0 = 4;
+        assert_eq!(vec.first(), Some(&A(3)));
+        assert_eq!(vec.last(), Some(&A(4)));
+    }
+
+    #[test]
+    fn raw_pointers() {
+        let mut vec: MyVec<u32> = MyVec::new();
+        assert!(!vec.as_ptr().is_null());
+        assert_eq!(vec.as_ptr() as usize % std::mem::align_of::<u32>(), 0);
+
+        vec.push(1);
+        unsafe { *vec.as_mut_ptr() = 2 };
+        assert_eq!(unsafe { *vec.as_ptr() }, 2);
+    }
+
+    #[test]
+    fn fill_elements() {
+        let mut vec: MyVec<u8> = MyVec::new();
+        vec.resize(4, 1);
+        vec.fill(7);
+        assert_eq!(vec.as_slice(), &[7, 7, 7, 7]);
+
+        let mut next = 0;
+        vec.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn reverse_in_place() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(A(i));
+        }
+
+        vec.reverse();
+        assert_eq!(vec.as_slice(), &[A(4), A(3), A(2), A(1), A(0)]);
+    }
+
+    #[test]
+    fn rotate_in_place() {
+        let mut vec = MyVec::new();
+        for i in 0..5 {
+            vec.push(A(i));
+        }
+
+        vec.rotate_left(2);
+        assert_eq!(vec.as_slice(), &[A(2), A(3), A(4), A(0), A(1)]);
+
+        vec.rotate_right(2);
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(2), A(3), A(4)]);
+
+        vec.rotate_left(5);
+        vec.rotate_right(0);
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(2), A(3), A(4)]);
+    }
+
+    #[test]
+    fn push_returning_reference() {
+        let mut vec = MyVec::new();
+        vec.push_get(A(1)).0 += 1;
+        vec.push_with(|| A(5)).0 *= 2;
+        assert_eq!(vec.as_slice(), &[A(2), A(10)]);
+    }
+
+    #[test]
+    fn from_elem_clones() {
+        let vec = MyVec::from_elem(String::from("a"), 3);
+        assert_eq!(vec.as_slice(), &["a", "a", "a"]);
+        assert_eq!(vec.capacity(), 3);
+
+        let empty = MyVec::from_elem(String::from("a"), 0);
+        assert!(empty.is_empty());
+
+        let zeroed = MyVec::zeroed(5);
+        assert_eq!(zeroed.as_slice(), &[0; 5]);
+        assert_eq!(zeroed.capacity(), 5);
+    }
+
+    #[test]
+    fn bulk_overwrite() {
+        let mut vec: MyVec<u32> = MyVec::from_elem(0, 4);
+        vec.copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+
+        vec.copy_within(..3, 1);
+        assert_eq!(vec.as_slice(), &[1, 1, 2, 3]);
+
+        let mut strings = MyVec::from_elem(String::new(), 2);
+        strings.clone_from_slice(&[String::from("a"), String::from("b")]);
+        assert_eq!(strings.as_slice(), &["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_within_out_of_bounds() {
+        let mut vec: MyVec<u32> = MyVec::from_elem(0, 4);
+        vec.copy_within(1..3, 3);
+    }
+
+    #[test]
+    fn swap_with_external_slice() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+        let mut other = [A(3), A(4)];
+
+        vec.swap_with_slice(&mut other);
+        assert_eq!(vec.as_slice(), &[A(3), A(4)]);
+        assert_eq!(other, [A(1), A(2)]);
+    }
+
+    #[test]
+    fn many_mutable_references() {
+        let mut vec = MyVec::new();
+        for i in 0..4 {
+            vec.push(A(i));
+        }
+
+        let [a, b] = vec.get_many_mut([3, 0]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(vec.as_slice(), &[A(3), A(1), A(2), A(0)]);
+
+        assert!(vec.get_many_mut([1, 1]).is_none());
+        assert!(vec.get_many_mut([0, 4]).is_none());
+        assert!(vec.get_many_mut([]).is_some());
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let mut vec = MyVec::new();
+        vec.push(A(1));
+        vec.push(A(2));
+
+        unsafe {
+            vec.get_unchecked_mut(0).0 = 3;
+            assert_eq!(vec.get_unchecked(0), &A(3));
+            assert_eq!(vec.get_unchecked(1), &A(2));
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn unchecked_access_debug_assert() {
+        let mut vec = MyVec::new();
+        vec.push(1);
+        unsafe { vec.get_unchecked(1) };
+    }
+
+    #[test]
+    fn remove_by_value() {
+        let mut vec = MyVec::new();
+        for i in [1, 2, 3, 2, 4] {
+            vec.push(A(i));
+        }
+
+        assert_eq!(vec.remove_item(&A(2)), Some(A(2)));
+        assert_eq!(vec.as_slice(), &[A(1), A(3), A(2), A(4)]);
+
+        assert_eq!(vec.swap_remove_item(&A(1)), Some(A(1)));
+        assert_eq!(vec.as_slice(), &[A(4), A(3), A(2)]);
+
+        assert_eq!(vec.remove_item(&A(7)), None);
+    }
+
+    #[test]
+    fn remove_many_indices() {
+        let mut vec = MyVec::new();
+        for i in 0..7 {
+            vec.push(A(i));
+        }
+
+        let removed = vec.remove_indices(&[1, 2, 5]);
+        assert_eq!(removed.as_slice(), &[A(1), A(2), A(5)]);
+        assert_eq!(vec.as_slice(), &[A(0), A(3), A(4), A(6)]);
+
+        assert!(vec.remove_indices(&[]).is_empty());
+        assert_eq!(vec.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_unsorted_indices() {
+        let mut vec = MyVec::from_elem(0, 4);
+        vec.remove_indices(&[2, 1]);
+    }
+
+    #[test]
+    fn checked_insert_and_remove() {
+        let mut vec = MyVec::new();
+        assert_eq!(vec.checked_insert(0, A(1)), Ok(()));
+        assert_eq!(vec.checked_insert(2, A(2)), Err(A(2)));
+        assert_eq!(vec.try_remove(1), None);
+        assert_eq!(vec.try_remove(0), Some(A(1)));
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn prefix_and_suffix() {
+        let mut vec = MyVec::new();
+        vec.extend_from_slice(b"\x02payload\x03");
+
+        assert!(vec.starts_with(b"\x02"));
+        assert!(vec.ends_with(b"\x03"));
+        assert!(!vec.starts_with(b"\x03"));
+
+        let body = vec.strip_prefix(b"\x02").unwrap();
+        assert_eq!(body, b"payload\x03");
+        assert_eq!(vec.strip_suffix(b"\x03"), Some(&b"\x02payload"[..]));
+        assert_eq!(vec.strip_prefix(b"x"), None);
+    }
+
+    #[test]
+    fn split_ends() {
+        let mut vec = MyVec::new();
+        assert!(vec.split_first().is_none());
+
+        for i in 0..3 {
+            vec.push(A(i));
+        }
+        assert_eq!(vec.split_first(), Some((&A(0), &[A(1), A(2)][..])));
+        assert_eq!(vec.split_last(), Some((&A(2), &[A(0), A(1)][..])));
+
+        let (first, rest) = vec.split_first_mut().unwrap();
+        first.0 = rest.len();
+        let (last, _) = vec.split_last_mut().unwrap();
+        last.0 = 7;
+        assert_eq!(vec.as_slice(), &[A(2), A(1), A(7)]);
+    }
+
+    #[test]
+    fn try_with_capacity_rejects_oversized() {
+        let vec: MyVec<u64> = MyVec::try_with_capacity(8).unwrap();
+        assert_eq!(vec.capacity(), 8);
+
+        let oversized = MyVec::<u64>::try_with_capacity(usize::MAX / 4);
+        assert_eq!(oversized.err(), Some(TryReserveError::CapacityOverflow));
+    }
+
+    #[test]
+    fn from_fn_indices() {
+        let vec = MyVec::from_fn(4, |i| A(i * i));
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(4), A(9)]);
+        assert_eq!(vec.capacity(), 4);
+    }
+
+    #[test]
+    fn from_fn_panic_drops_created() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+        struct Counted(#[allow(dead_code)] u8);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let result = std::panic::catch_unwind(|| {
+            MyVec::from_fn(5, |i| {
+                assert_ne!(i, 3);
+                Counted(0)
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn repeat_contents() {
+        let mut vec = MyVec::new();
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+
+        let repeated = vec.repeat(3);
+        assert_eq!(repeated.as_slice(), &["a", "b", "a", "b", "a", "b"]);
+        assert_eq!(repeated.capacity(), 6);
+
+        assert!(vec.repeat(0).is_empty());
+        assert_eq!(vec.repeat(1).as_slice(), vec.as_slice());
+    }
+
+    #[test]
+    fn try_into_array() {
+        let mut vec = MyVec::new();
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+
+        let Err(vec) = <[String; 3]>::try_from(vec) else {
+            panic!("length mismatch should return the vector");
+        };
+        assert_eq!(vec.len(), 2);
+
+        let Ok(array) = <[String; 2]>::try_from(vec) else {
+            panic!("lengths should match");
+        };
+        assert_eq!(array, ["a", "b"]);
+    }
+
+    #[test]
+    fn promote_uninit() {
+        let mut vec = MyVec::<String>::new_uninit(3);
+        assert_eq!(vec.len(), 3);
+        for (i, slot) in vec.as_mut_slice().iter_mut().enumerate() {
+            slot.write(i.to_string());
+        }
+
+        let vec = unsafe { vec.assume_init() };
+        assert_eq!(vec.as_slice(), &["0", "1", "2"]);
+    }
+
+    #[test]
+    fn iterate_by_reference() {
+        let mut vec = MyVec::from_fn(4, A);
+        for element in vec.iter_mut() {
+            element.0 *= 2;
+        }
+
+        let sum: usize = vec.iter().map(|element| element.0).sum();
+        assert_eq!(sum, 12);
+        assert_eq!(vec.iter().next_back(), Some(&A(6)));
+    }
+
+    #[test]
+    fn for_loop_over_references() {
+        let mut vec = MyVec::from_fn(3, A);
+        for element in &mut vec {
+            element.0 += 1;
+        }
+
+        let mut sum = 0;
+        for element in &vec {
+            sum += element.0;
+        }
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn collect_into_vec() {
+        let vec: MyVec<String> = (0..5).map(|i| i.to_string()).collect();
+        assert_eq!(vec.as_slice(), &["0", "1", "2", "3", "4"]);
+        assert_eq!(vec.capacity(), 5);
+
+        let filtered: MyVec<usize> = (0..10).filter(|i| i % 3 == 0).collect();
+        assert_eq!(filtered.as_slice(), &[0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn extend_from_iterator() {
+        let mut vec = MyVec::new();
+        vec.push(A(0));
+        vec.extend((1..4).map(A));
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(2), A(3)]);
+        assert_eq!(vec.capacity(), 4);
+
+        vec.extend((4..10).filter(|i| i % 2 == 0).map(A));
+        assert_eq!(vec.as_slice(), &[A(0), A(1), A(2), A(3), A(4), A(6), A(8)]);
+    }
+
+    #[test]
+    fn extend_by_reference() {
+        let mut vec: MyVec<u32> = MyVec::new();
+        let source = [1, 2, 3];
+        vec.extend(source.iter());
+        vec.extend(&source[..1]);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn index_operator() {
+        let mut vec = MyVec::from_fn(3, A);
+        vec[1].0 = 5;
+        assert_eq!(vec[1], A(5));
+        assert_eq!(vec[2], A(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the index is 3")]
+    fn index_out_of_bounds() {
+        let vec = MyVec::from_fn(3, A);
+        let _ = &vec[3];
+    }
+
+    #[test]
+    fn index_by_range() {
+        let mut vec = MyVec::from_fn(6, |i| i);
+        assert_eq!(&vec[2..5], &[2, 3, 4]);
+        assert_eq!(&vec[..2], &[0, 1]);
+        assert_eq!(&vec[4..], &[4, 5]);
+        assert_eq!(&vec[..], vec.as_slice());
+        assert_eq!(&vec[1..=2], &[1, 2]);
+        assert_eq!(&vec[..=0], &[0]);
+
+        vec[3..].fill(9);
+        assert_eq!(vec.as_slice(), &[0, 1, 2, 9, 9, 9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_by_range_out_of_bounds() {
+        let vec = MyVec::from_fn(3, |i| i);
+        let _ = &vec[1..4];
+    }
+
+    #[test]
+    fn deref_to_slice() {
+        fn total(elements: &[usize]) -> usize {
+            elements.iter().sum()
+        }
+
+        let mut vec: MyVec<usize> = [3, 1, 2].into_iter().collect();
+        vec.sort_unstable();
+        assert_eq!(*vec, [1, 2, 3]);
+        assert_eq!(total(&vec), 6);
+        assert_eq!(vec.chunks(2).count(), 2);
+    }
+
+    #[test]
+    fn clone_vec() {
+        let vec: MyVec<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+
+        let clone = vec.clone();
+        assert_eq!(clone.as_slice(), vec.as_slice());
+        assert_eq!(clone.capacity(), 2);
+    }
+
+    #[test]
+    fn clone_from_reuses_allocation() {
+        let source: MyVec<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut target: MyVec<String> = MyVec::with_capacity(8);
+        target.push("x".to_string());
+        target.push("y".to_string());
+        target.push("z".to_string());
+        let pointer = target.as_ptr();
+
+        target.clone_from(&source);
+        assert_eq!(target.as_slice(), &["a", "b"]);
+        assert_eq!(target.capacity(), 8);
+        assert_eq!(target.as_ptr(), pointer);
+
+        let mut small: MyVec<String> = MyVec::new();
+        small.clone_from(&source);
+        assert_eq!(small.as_slice(), &["a", "b"]);
+    }
+
+    #[test]
+    fn debug_like_slice() {
+        let vec: MyVec<usize> = [1, 2, 3].into_iter().collect();
+        assert_eq!(format!("{vec:?}"), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", MyVec::<usize>::new()), "[]");
+    }
+
+    #[test]
+    fn compare_with_other_containers() {
+        let vec: MyVec<usize> = [1, 2, 3].into_iter().collect();
+        let same: MyVec<usize> = [1, 2, 3].into_iter().collect();
+        let slice: &[usize] = &[1, 2, 3];
+
+        assert_eq!(vec, same);
+        assert_eq!(vec, slice);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec, vec![1, 2, 3]);
+        assert_eq!(slice, vec);
+        assert_eq!([1, 2, 3], vec);
+        assert_eq!(vec![1, 2, 3], vec);
+        assert_ne!(vec, [1, 2]);
+        assert_ne!(vec, MyVec::new());
+    }
+
+    #[test]
+    fn ordered_lexicographically() {
+        let short: MyVec<usize> = [1, 2].into_iter().collect();
+        let long: MyVec<usize> = [1, 2, 0].into_iter().collect();
+        let bigger: MyVec<usize> = [1, 3].into_iter().collect();
+        assert!(short < long);
+        assert!(long < bigger);
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(bigger, "bigger");
+        map.insert(short, "short");
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            ["short", "bigger"]
+        );
+    }
+
+    #[test]
+    fn hash_as_map_key() {
+        use std::hash::BuildHasher;
+
+        let vec: MyVec<usize> = [1, 2, 3].into_iter().collect();
+        let state = std::collections::hash_map::RandomState::new();
+        assert_eq!(
+            state.hash_one(&vec),
+            state.hash_one([1_usize, 2, 3].as_slice())
+        );
+
+        let mut map = std::collections::HashMap::new();
+        map.insert(vec, "value");
+        let key: MyVec<usize> = [1, 2, 3].into_iter().collect();
+        assert_eq!(map.get(&key), Some(&"value"));
+    }
+
+    #[test]
+    fn default_is_empty() {
+        #[derive(Default)]
+        struct Holder {
+            values: MyVec<usize>,
+        }
+
+        let mut holder = Holder::default();
+        assert!(holder.values.is_empty());
+        assert_eq!(holder.values.capacity(), 0);
+
+        holder.values.push(1);
+        let taken = std::mem::take(&mut holder.values);
+        assert_eq!(taken, [1]);
+        assert!(holder.values.is_empty());
+    }
+
+    #[test]
+    fn from_slice_and_array() {
+        let slice = ["a".to_string(), "b".to_string()];
+        let cloned = MyVec::from(slice.as_slice());
+        assert_eq!(cloned, slice);
+        assert_eq!(cloned.capacity(), 2);
+
+        let moved = MyVec::from(slice);
+        assert_eq!(moved, ["a", "b"].map(String::from));
+
+        let empty = MyVec::<String>::from([]);
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn std_vec_roundtrip_keeps_buffer() {
+        let mut std_vec = Vec::with_capacity(10);
+        std_vec.extend(["a", "b"].map(String::from));
+        let pointer = std_vec.as_ptr();
+
+        let vec = MyVec::from(std_vec);
+        assert_eq!(vec, ["a", "b"]);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec.as_ptr(), pointer);
+
+        let std_vec: Vec<String> = vec.into();
+        assert_eq!(std_vec, ["a", "b"]);
+        assert_eq!(std_vec.as_ptr(), pointer);
+
+        let empty: Vec<String> = MyVec::new().into();
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn borrow_as_slice() {
+        fn first<S: AsRef<[usize]>>(elements: S) -> Option<usize> {
+            elements.as_ref().first().copied()
+        }
+
+        let mut vec: MyVec<usize> = [1, 2].into_iter().collect();
+        assert_eq!(first(&vec), Some(1));
+        AsMut::<[usize]>::as_mut(&mut vec)[0] = 5;
+        assert_eq!(first(vec), Some(5));
+
+        let mut map = std::collections::HashMap::new();
+        map.insert(MyVec::from([1_usize, 2]), "value");
+        assert_eq!(map.get([1_usize, 2].as_slice()), Some(&"value"));
+    }
+
+    #[test]
+    fn write_bytes() {
+        use std::io::Write;
+
+        let mut vec = MyVec::new();
+        assert_eq!(vec.write(b"ab").unwrap(), 2);
+        write!(vec, "-{}-", 42).unwrap();
+        let bufs = [io::IoSlice::new(b"c"), io::IoSlice::new(b"de")];
+        assert_eq!(vec.write_vectored(&bufs).unwrap(), 3);
+        vec.flush().unwrap();
+        assert_eq!(vec, *b"ab-42-cde");
+    }
+
+    #[test]
+    fn my_vec_macro() {
+        let empty: MyVec<String> = crate::my_vec![];
+        assert_eq!(empty.capacity(), 0);
+
+        let listed = crate::my_vec![A(1), A(2), A(3),];
+        assert_eq!(listed, [A(1), A(2), A(3)]);
+        assert_eq!(listed.capacity(), 3);
+
+        let repeated = crate::my_vec!["a".to_string(); 2];
+        assert_eq!(repeated, ["a", "a"]);
+        assert_eq!(repeated.capacity(), 2);
+    }
+
+    #[test]
+    fn send_and_sync_follow_element() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MyVec<usize>>();
+        assert_send_sync::<MyVec<String>>();
+
+        let vec = MyVec::from([1_usize, 2, 3]);
+        let sum = std::thread::spawn(move || vec.iter().sum::<usize>())
+            .join()
+            .unwrap();
+        assert_eq!(sum, 6);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn may_dangle_allows_dead_borrows() {
+        // NOTE: `value` is dropped before `vec`, which only compiles with the eyepatch.
+        let mut vec = MyVec::new();
+        let value = String::from("value");
+        vec.push(&value);
+        assert_eq!(vec, [&value]);
+    }
+
+    #[test]
+    fn bytes_from_strings() {
+        let string = String::with_capacity(16) + "hello";
+        let pointer = string.as_ptr();
+
+        let owned = MyVec::from(string);
+        assert_eq!(owned, *b"hello");
+        assert_eq!(owned.capacity(), 16);
+        assert_eq!(owned.as_ptr(), pointer);
+
+        let copied = MyVec::from("héllo");
+        assert_eq!(copied, "héllo".as_bytes());
+        assert_eq!(copied.capacity(), 6);
+    }
+
+    #[test]
+    fn into_shared_slices() {
+        let vec = MyVec::from(["a".to_string(), "b".to_string()]);
+
+        let boxed: Box<[String]> = vec.clone().into();
+        assert_eq!(*boxed, ["a", "b"]);
+
+        let rc: std::rc::Rc<[String]> = vec.clone().into();
+        assert_eq!(*rc, ["a", "b"]);
+
+        let arc: std::sync::Arc<[String]> = vec.into();
+        assert_eq!(*arc, ["a", "b"]);
+    }
+
+    #[test]
+    fn add_concatenates() {
+        let mut left = MyVec::with_capacity(8);
+        left.push("a".to_string());
+        let pointer = left.as_ptr();
+
+        let joined = left + MyVec::from(["b".to_string()]);
+        assert_eq!(joined, ["a", "b"]);
+        assert_eq!(joined.as_ptr(), pointer);
+
+        let mut joined = joined + ["c".to_string()].as_slice();
+        joined += ["d".to_string()].as_slice();
+        joined += MyVec::from(["e".to_string()]);
+        assert_eq!(joined, ["a", "b", "c", "d", "e"]);
+        assert_eq!(joined.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn zero_sized_elements() {
+        let mut vec = MyVec::new();
+        assert_eq!(vec.capacity(), usize::MAX);
+
+        for _ in 0..10 {
+            vec.push(());
+        }
+        vec.insert(3, ());
+        assert_eq!(vec.len(), 11);
+        assert_eq!(vec.pop(), Some(()));
+        assert_eq!(vec.remove(0), ());
+        assert_eq!(vec.drain(..4).count(), 4);
+
+        vec.shrink_to_fit();
+        vec.reserve(100);
+        assert_eq!(vec.capacity(), usize::MAX);
+        assert_eq!(vec.iter().count(), 5);
+        assert_eq!(vec.into_iter().rev().count(), 5);
+
+        assert_eq!(MyVec::<()>::with_capacity(3).capacity(), usize::MAX);
+        assert!(MyVec::from([(); 1]).try_reserve(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn zero_sized_drops_counted() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+        struct Marker;
+        impl Drop for Marker {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut vec = MyVec::new();
+        for _ in 0..6 {
+            vec.push(Marker);
+        }
+        vec.truncate(4);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+
+        let mut iter = vec.into_iter();
+        iter.next();
+        drop(iter);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 6);
+
+        let std_vec: Vec<Marker> = MyVec::from_fn(3, |_| Marker).into();
+        let vec = MyVec::from(std_vec);
+        assert_eq!(vec.len(), 3);
+        drop(vec);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 9);
+
+        let result = std::panic::catch_unwind(|| {
+            MyVec::from_fn(5, |i| {
+                assert_ne!(i, 3);
+                Marker
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 12);
     }
 }
@@ -0,0 +1,67 @@
+//! [`proptest`] support, enabled with the `proptest` feature.
+//!
+//! Use [`my_vec`] for vectors with custom elements or lengths, or `any::<MyVec<T>>()` when the
+//! element type implements [`Arbitrary`] itself.
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+
+use crate::MyVec;
+
+/// Strategy returned by [`my_vec`].
+pub type MyVecStrategy<S> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> MyVec<<S as Strategy>::Value>>;
+
+/// Creates a strategy generating vectors with a length in `size` and elements from `element`.
+///
+/// Failing cases shrink like the ones from `proptest::collection::vec`, elements are removed
+/// first and then the remaining ones are simplified.
+pub fn my_vec<S: Strategy>(element: S, size: impl Into<SizeRange>) -> MyVecStrategy<S> {
+    vec(element, size).prop_map(MyVec::from as fn(_) -> _)
+}
+
+impl<T: Arbitrary> Arbitrary for MyVec<T> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = MyVecStrategy<T::Strategy>;
+
+    fn arbitrary_with((size, element): Self::Parameters) -> Self::Strategy {
+        my_vec(any_with::<T>(element), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::test_runner::{TestError, TestRunner};
+
+    use super::my_vec;
+    use crate::MyVec;
+
+    proptest! {
+        #[test]
+        fn lengths_in_range(vec in my_vec(any::<u8>(), 2..5)) {
+            prop_assert!((2..5).contains(&vec.len()));
+        }
+
+        #[test]
+        fn matches_std_vec(vec in any::<MyVec<u16>>()) {
+            let std_vec: Vec<u16> = vec.clone().into();
+            prop_assert_eq!(vec, std_vec);
+        }
+    }
+
+    #[test]
+    fn shrinks_to_minimal_case() {
+        let mut runner = TestRunner::default();
+        let result = runner.run(&my_vec(any::<u8>(), 0..10), |vec| {
+            prop_assert!(vec.len() < 3);
+            Ok(())
+        });
+
+        let Err(TestError::Fail(_, minimal)) = result else {
+            panic!("property should fail");
+        };
+        assert_eq!(minimal, [0, 0, 0]);
+    }
+}
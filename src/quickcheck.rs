@@ -0,0 +1,53 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::MyVec;
+
+impl<T: Arbitrary> Arbitrary for MyVec<T> {
+    /// Generates the same vectors as for std `Vec`, with a length up to the size of `g`.
+    fn arbitrary(g: &mut Gen) -> Self {
+        MyVec::from(Vec::arbitrary(g))
+    }
+
+    /// Shrinks by truncating and removing elements first, then by shrinking the elements.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_vec().shrink().map(MyVec::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    use crate::MyVec;
+
+    #[test]
+    fn length_bounded_by_size() {
+        let mut g = Gen::new(8);
+        for _ in 0..100 {
+            assert!(MyVec::<u8>::arbitrary(&mut g).len() <= 8);
+        }
+    }
+
+    #[test]
+    fn shrinks_towards_simpler_vectors() {
+        let vec = MyVec::from([5_u8, 9]);
+
+        let shrunk: Vec<MyVec<u8>> = vec.shrink().collect();
+        assert!(shrunk.contains(&MyVec::new()));
+        assert!(shrunk
+            .iter()
+            .all(|smaller| smaller.len() <= vec.len() && *smaller != vec));
+    }
+
+    #[test]
+    fn reverse_twice_is_identity() {
+        fn property(vec: MyVec<u32>) -> bool {
+            let mut reversed = vec.clone();
+            reversed.reverse();
+            reversed.reverse();
+            reversed == vec
+        }
+
+        quickcheck(property as fn(MyVec<u32>) -> bool);
+    }
+}
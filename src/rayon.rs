@@ -0,0 +1,123 @@
+use std::collections::LinkedList;
+
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+
+use crate::MyVec;
+
+impl<T: Send> IntoParallelIterator for MyVec<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    /// Moves the elements out in parallel.
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a MyVec<T> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut MyVec<T> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for MyVec<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        let mut vec = MyVec::new();
+        vec.par_extend(par_iter);
+        vec
+    }
+}
+
+impl<T: Send> ParallelExtend<T> for MyVec<T> {
+    /// Every rayon job pushes into its own buffer, the buffers are then moved into the vector one
+    /// after another with a single reservation up front. Order of the elements is kept.
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        let buffers = par_iter
+            .into_par_iter()
+            .fold(MyVec::new, |mut buffer, element| {
+                buffer.push(element);
+                buffer
+            })
+            .map(|buffer| LinkedList::from([buffer]))
+            .reduce(LinkedList::new, |mut left, mut right| {
+                left.append(&mut right);
+                left
+            });
+
+        self.reserve(buffers.iter().map(MyVec::len).sum());
+        for mut buffer in buffers {
+            // NOTE: Each buffer is moved in with a single `memcpy`.
+            self.append(&mut buffer);
+        }
+    }
+}
+
+impl<'a, T: Copy + Send + Sync + 'a> ParallelExtend<&'a T> for MyVec<T> {
+    fn par_extend<I: IntoParallelIterator<Item = &'a T>>(&mut self, par_iter: I) {
+        self.par_extend(par_iter.into_par_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::MyVec;
+
+    #[test]
+    fn par_iter_by_reference() {
+        let mut vec: MyVec<u64> = (1..=1000).collect();
+
+        assert_eq!(vec.par_iter().sum::<u64>(), 500_500);
+        vec.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(vec.par_iter().filter(|&&x| x % 4 == 0).count(), 500);
+    }
+
+    #[test]
+    fn into_par_iter_by_value() {
+        let vec: MyVec<String> = (0..100).map(|i| i.to_string()).collect();
+
+        let mut lengths: Vec<usize> = vec.into_par_iter().map(|s| s.len()).collect();
+        lengths.sort_unstable();
+        assert_eq!(lengths.iter().filter(|&&len| len == 1).count(), 10);
+        assert_eq!(lengths.len(), 100);
+    }
+
+    #[test]
+    fn collect_keeps_order() {
+        let vec: MyVec<u32> = (0..10_000).into_par_iter().map(|x| x * 2).collect();
+        assert_eq!(vec.len(), 10_000);
+        assert!(vec.iter().enumerate().all(|(i, &x)| x == i as u32 * 2));
+
+        let odd: MyVec<u32> = vec
+            .par_iter()
+            .map(|x| x + 1)
+            .filter(|x| x % 4 == 1)
+            .collect();
+        assert_eq!(odd.first(), Some(&1));
+        assert_eq!(odd.len(), 5_000);
+    }
+
+    #[test]
+    fn par_extend_appends() {
+        let mut vec = MyVec::from(["a".to_string()]);
+        vec.par_extend(["b", "c"].into_par_iter().map(String::from));
+        assert_eq!(vec, ["a", "b", "c"]);
+
+        let mut numbers = MyVec::from([1, 2]);
+        numbers.par_extend(&[3, 4]);
+        assert_eq!(numbers, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,66 @@
+use rkyv::ser::{ScratchSpace, Serializer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, DeserializeUnsized, Fallible, Serialize};
+
+use crate::MyVec;
+
+/// Archived the same way as std `Vec`, as a relative pointer to the archived elements. Archived
+/// data is accessed in place as a slice without deserializing.
+impl<T: Archive> Archive for MyVec<T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    unsafe fn resolve(&self, pos: usize, resolver: VecResolver, out: *mut Self::Archived) {
+        unsafe { ArchivedVec::resolve_from_slice(self.as_slice(), pos, resolver, out) };
+    }
+}
+
+impl<T, S> Serialize<S> for MyVec<T>
+where
+    T: Serialize<S>,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<VecResolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<T, D> Deserialize<MyVec<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    D: Fallible + ?Sized,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<MyVec<T>, D::Error> {
+        Deserialize::<Vec<T>, D>::deserialize(self, deserializer).map(MyVec::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{archived_root, Deserialize, Infallible};
+
+    use crate::MyVec;
+
+    #[test]
+    fn archive_roundtrip() {
+        let vec = MyVec::from([1_u32, 2, 3]);
+
+        let bytes = rkyv::to_bytes::<_, 256>(&vec).unwrap();
+        let archived = unsafe { archived_root::<MyVec<u32>>(&bytes) };
+        assert_eq!(archived.as_slice(), [1, 2, 3]);
+
+        let deserialized: MyVec<u32> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, vec);
+    }
+
+    #[test]
+    fn archive_nested_strings() {
+        let vec = MyVec::from(["a".to_string(), "bc".to_string()]);
+
+        let bytes = rkyv::to_bytes::<_, 256>(&vec).unwrap();
+        let archived = unsafe { archived_root::<MyVec<String>>(&bytes) };
+        assert_eq!(archived.len(), 2);
+        assert_eq!(archived[1], "bc");
+    }
+}
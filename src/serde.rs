@@ -0,0 +1,70 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::MyVec;
+
+/// Upper bound on what is preallocated from a size hint, untrusted input mustn't be able to
+/// request huge allocations up front.
+const MAX_PREALLOCATION_BYTES: usize = 1024 * 1024;
+
+impl<T: Serialize> Serialize for MyVec<T> {
+    /// Serialized as a sequence, the same as a slice or std `Vec`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MyVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MyVecVisitor(PhantomData))
+    }
+}
+
+struct MyVecVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for MyVecVisitor<T> {
+    type Value = MyVec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    /// Preallocates from the size hint of the format, so the elements are pushed without
+    /// reallocating.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let limit = MAX_PREALLOCATION_BYTES / size_of::<T>().max(1);
+        let mut vec = MyVec::with_capacity(seq.size_hint().unwrap_or(0).min(limit));
+        while let Some(element) = seq.next_element()? {
+            vec.push(element);
+        }
+        Ok(vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Token};
+
+    use crate::MyVec;
+
+    #[test]
+    fn tokens_roundtrip() {
+        let vec = MyVec::from([1_u32, 2]);
+        assert_tokens(
+            &vec,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U32(1),
+                Token::U32(2),
+                Token::SeqEnd,
+            ],
+        );
+
+        let empty: MyVec<u32> = MyVec::new();
+        assert_tokens(&empty, &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
+    }
+}
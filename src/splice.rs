@@ -0,0 +1,165 @@
+use std::ptr;
+
+use crate::{Allocator, Drain, Global, MyVec};
+
+/// Iterator that replaces a range of a [`MyVec`] with the elements of another iterator, yielding
+/// the removed elements.
+///
+/// Created by [`MyVec::splice`]. The replacement is inserted when the iterator is dropped, even if
+/// the removed elements weren't all consumed.
+pub struct Splice<'a, I: Iterator, A: Allocator = Global> {
+    drain: Drain<'a, I::Item, A>,
+    replace_with: I,
+}
+
+impl<'a, I: Iterator, A: Allocator> Splice<'a, I, A> {
+    pub(crate) fn new(drain: Drain<'a, I::Item, A>, replace_with: I) -> Self {
+        Self {
+            drain,
+            replace_with,
+        }
+    }
+}
+
+impl<I: Iterator, A: Allocator> Iterator for Splice<'_, I, A> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<I: Iterator, A: Allocator> DoubleEndedIterator for Splice<'_, I, A> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<I: Iterator, A: Allocator> ExactSizeIterator for Splice<'_, I, A> {}
+
+impl<I: Iterator, A: Allocator> Drop for Splice<'_, I, A> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+
+        // NOTE: Without a tail there's no gap to fill, we can append right away.
+        if self.drain.tail_len == 0 {
+            for element in self.replace_with.by_ref() {
+                self.drain.vec.push(element);
+            }
+            return;
+        }
+
+        // NOTE: First fill the gap left by the removed range.
+        if !self.drain.fill(&mut self.replace_with) {
+            return;
+        }
+
+        // NOTE: There may be more elements, use the lower bound as an estimate to move the tail.
+        let (lower_bound, _) = self.replace_with.size_hint();
+        if lower_bound > 0 {
+            self.drain.move_tail(lower_bound);
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+        }
+
+        // NOTE: Collect whatever is left so we know exactly how far the tail has to move.
+        let mut collected = MyVec::new();
+        for element in self.replace_with.by_ref() {
+            collected.push(element);
+        }
+        if !collected.is_empty() {
+            self.drain.move_tail(collected.len());
+            let filled = self.drain.fill(&mut collected.drain(..));
+            debug_assert!(filled);
+        }
+        // NOTE: `Drain::drop` moves the tail back next to the inserted elements.
+    }
+}
+
+impl<T, A: Allocator> Drain<'_, T, A> {
+    /// Writes elements from `replace_with` into the gap between the vector's length and the tail.
+    /// Returns `true` if the whole gap was filled.
+    fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
+        let vec = &mut *self.vec;
+        while vec.length < self.tail_start {
+            match replace_with.next() {
+                Some(element) => {
+                    unsafe { vec.pointer.as_ptr().add(vec.length).write(element) };
+                    vec.length += 1;
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Moves the tail `additional` slots further back, growing the vector when needed.
+    fn move_tail(&mut self, additional: usize) {
+        let vec = &mut *self.vec;
+        let used = self.tail_start + self.tail_len;
+        vec.reserve(used - vec.length + additional);
+
+        let new_tail_start = self.tail_start + additional;
+        unsafe {
+            let base = vec.pointer.as_ptr();
+            ptr::copy(
+                base.add(self.tail_start),
+                base.add(new_tail_start),
+                self.tail_len,
+            );
+        }
+        self.tail_start = new_tail_start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MyVec;
+
+    #[test]
+    fn splice_same_length() {
+        let mut vec = MyVec::from(["a", "b", "c", "d"].map(String::from));
+
+        let removed: Vec<String> = vec
+            .splice(1..3, MyVec::from(["x", "y"].map(String::from)).drain(..))
+            .collect();
+        assert_eq!(removed, ["b", "c"]);
+        assert_eq!(vec.as_slice(), &["a", "x", "y", "d"]);
+    }
+
+    #[test]
+    fn splice_longer_replacement() {
+        let mut vec = MyVec::from(["a", "b", "c"].map(String::from));
+
+        // NOTE: `filter` has a lower bound of zero, so the tail is moved by collecting.
+        let replacement = ["x", "y", "z", "w"].map(String::from);
+        vec.splice(1..2, replacement.into_iter().filter(|_| true));
+        assert_eq!(vec.as_slice(), &["a", "x", "y", "z", "w", "c"]);
+
+        vec.splice(..1, ["1", "2"].map(String::from));
+        assert_eq!(vec.as_slice(), &["1", "2", "x", "y", "z", "w", "c"]);
+    }
+
+    #[test]
+    fn splice_shorter_replacement() {
+        let mut vec = MyVec::from(["a", "b", "c", "d"].map(String::from));
+
+        let mut splice = vec.splice(..3, [String::from("x")]);
+        assert_eq!(splice.next().as_deref(), Some("a"));
+        drop(splice);
+        assert_eq!(vec.as_slice(), &["x", "d"]);
+    }
+
+    #[test]
+    fn splice_at_end() {
+        let mut vec = MyVec::from(["a", "b"].map(String::from));
+
+        vec.splice(1.., ["x", "y", "z"].map(String::from));
+        assert_eq!(vec.as_slice(), &["a", "x", "y", "z"]);
+    }
+}
@@ -0,0 +1,97 @@
+use zeroize::{DefaultIsZeroes, Zeroize, ZeroizeOnDrop};
+
+use crate::MyVec;
+
+/// Buffers are only wiped when they're freed. Growing or shrinking the vector can move the
+/// elements to a new buffer and leave a copy behind in the old one, so reserve the full capacity
+/// for secrets up front.
+impl<Z: Zeroize> Zeroize for MyVec<Z> {
+    /// Zeroizes the elements, clears the vector and then zeroes the whole buffer, spare capacity
+    /// included.
+    fn zeroize(&mut self) {
+        self.as_mut_slice().iter_mut().zeroize();
+        self.clear();
+        self.spare_capacity_mut().zeroize();
+    }
+}
+
+// NOTE: With the feature enabled, every vector zeroes its whole buffer before freeing it. Plain
+// values like bytes and integers have nothing else that could keep a copy.
+impl<Z: DefaultIsZeroes> ZeroizeOnDrop for MyVec<Z> {}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    use crate::{Allocator, Global, MyVec};
+
+    /// Records whether the freed blocks held only zeros.
+    #[derive(Default)]
+    struct Inspecting {
+        freed: Cell<usize>,
+        wiped: Cell<usize>,
+    }
+
+    unsafe impl Allocator for Inspecting {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, pointer: NonNull<u8>, layout: Layout) {
+            let block = unsafe { std::slice::from_raw_parts(pointer.as_ptr(), layout.size()) };
+            self.freed.set(self.freed.get() + 1);
+            if block.iter().all(|&byte| byte == 0) {
+                self.wiped.set(self.wiped.get() + 1);
+            }
+            unsafe { Global.deallocate(pointer, layout) }
+        }
+    }
+
+    #[test]
+    fn zeroize_wipes_capacity() {
+        let mut vec: MyVec<u8> = MyVec::with_capacity(8);
+        vec.extend_from_slice(b"secret");
+        // NOTE: Bytes written past the length are still in the buffer until wiped.
+        vec.spare_capacity_mut()[0].write(0xff);
+
+        vec.zeroize();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 8);
+        let buffer = unsafe { std::slice::from_raw_parts(vec.as_ptr(), vec.capacity()) };
+        assert!(buffer.iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn wiped_on_drop() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<MyVec<u8>>();
+        assert_zeroize_on_drop::<MyVec<u64>>();
+
+        let key = Zeroizing::new(MyVec::from(*b"key"));
+        assert_eq!(*key, *b"key");
+    }
+
+    #[test]
+    fn buffer_zeroed_before_free() {
+        let inspecting = Inspecting::default();
+        let mut vec = MyVec::with_capacity_in(16, &inspecting);
+        vec.extend_from_slice(b"secret");
+        vec.spare_capacity_mut()[0].write(0xff);
+        drop(vec);
+        assert_eq!(inspecting.freed.get(), 1);
+        assert_eq!(inspecting.wiped.get(), 1);
+
+        let mut keys = MyVec::with_capacity_in(2, &inspecting);
+        keys.push(u64::MAX);
+        keys.push(7);
+        let mut iter = keys.into_iter();
+        assert_eq!(iter.next(), Some(u64::MAX));
+        drop(iter);
+        assert_eq!(inspecting.freed.get(), 2);
+        assert_eq!(inspecting.wiped.get(), 2);
+    }
+}